@@ -0,0 +1,48 @@
+use std::f32::consts::PI;
+
+use painter::{
+  Config, Figure, ScaleStrategy,
+  primitive::{self, Annotation, ArrowHead, ArrowStyle, Coords, Curve, HAlign, Text, VAlign},
+  utils,
+};
+
+fn main() {
+  let mut figure = Figure::new(Config::default());
+  let axis = figure.add_subplot((1, 1))[0];
+  let axis = figure.axis(axis);
+  axis.set_strategy(ScaleStrategy::Stretch);
+
+  let t = utils::linspace(0.0, 12.0, 300);
+  let mut curve = Curve::new("signal".to_string(), primitive::Config::default());
+  curve.set_fn(&t, |v| 5.0 * (v / 2.0).sin());
+  axis.add(Box::new(curve));
+
+  // 指向数据点的注释：直线箭头 + 背景框
+  let mut peak = Annotation::new("maximum\ny = 5".to_string(), primitive::Config::default());
+  peak.set_target(PI, 5.0, Coords::Data);
+  peak.set_text_position(6.0, 3.0, Coords::Data);
+  peak.label().set_background(Some([60, 60, 70, 255]));
+  axis.add(Box::new(peak));
+
+  // 弯曲箭头 + 开口箭头
+  let mut valley = Annotation::new("minimum".to_string(), primitive::Config::default());
+  valley.set_target(3.0 * PI, -5.0, Coords::Data);
+  valley.set_text_position(6.0, -3.0, Coords::Data);
+  valley.set_arrow(ArrowStyle::Curved(0.3), ArrowHead::Open);
+  axis.add(Box::new(valley));
+
+  // 文字位置使用绘图区域的比例坐标
+  let mut note = Text::new("period 12.6".to_string(), primitive::Config::default());
+  note.set_position(0.98, 0.98, Coords::Axes);
+  note.set_align(HAlign::Right, VAlign::Top);
+  axis.add(Box::new(note));
+
+  let mut tilted = Text::new("rising".to_string(), primitive::Config::default());
+  tilted.set_position(11.0, -3.0, Coords::Data);
+  tilted.set_align(HAlign::Center, VAlign::Center);
+  tilted.set_rotation(60.0);
+  tilted.set_font_size(16.0);
+  axis.add(Box::new(tilted));
+
+  figure.show();
+}
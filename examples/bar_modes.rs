@@ -0,0 +1,36 @@
+use painter::{
+  Config, Figure,
+  primitive::{BarMode, Histrogram, Orientation},
+};
+
+fn main() {
+  let mut figure = Figure::new(Config::default());
+  figure.add_subplot((1, 2));
+
+  let profit: [f32; 5] = [3., 5., -2., 4., 1.];
+  let cost: [f32; 5] = [2., -1., -3., 2., 2.];
+
+  // 左图：堆叠柱状图，包含负值堆叠，并显示数值
+  let mut stacked = Histrogram::new("stacked".to_string());
+  stacked.set_data_norm(&profit);
+  stacked.set_data(&cost);
+  stacked.set_mode(BarMode::Stacked);
+  stacked.set_value_labels(true);
+  let ax = figure.nth(0).unwrap();
+  ax.add(Box::new(stacked));
+  ax.set_strategy(painter::ScaleStrategy::Stretch);
+
+  // 右图：水平分组柱状图，柱之间留空隙
+  let mut horizontal = Histrogram::new("horizontal".to_string());
+  horizontal.set_data_norm(&profit.map(f32::abs));
+  horizontal.set_data(&cost.map(f32::abs));
+  horizontal.set_orientation(Orientation::Horizontal);
+  horizontal.set_bar_width(0.9);
+  horizontal.set_bar_gap(0.2);
+  horizontal.set_value_labels(true);
+  let ax = figure.nth(1).unwrap();
+  ax.add(Box::new(horizontal));
+  ax.set_strategy(painter::ScaleStrategy::Stretch);
+
+  figure.show();
+}
@@ -0,0 +1,35 @@
+use painter::{
+  Config, Figure,
+  primitive::{self, Curve, ErrorStyle, Scatter},
+  utils,
+};
+
+fn main() {
+  let mut figure = Figure::new(Config::default());
+
+  // 曲线 + 半透明误差带
+  let x = utils::linspace(0., 6., 25);
+  let mut curve = Curve::new("fit".to_string(), primitive::Config::default());
+  curve.set_fn(&x, |v| v.sin() * 2. + 3.);
+  let err: Vec<f32> = x.iter().map(|v| 0.2 + v * 0.05).collect();
+  curve.set_yerr(&err);
+  curve.set_err_style(ErrorStyle::Band);
+
+  // 散点 + 非对称误差线
+  let mut scatter = Scatter::new("measure".to_string(), primitive::Config::default());
+  let xs = [0.5, 1.5, 2.5, 3.5, 4.5, 5.5];
+  scatter.set_x(&xs);
+  scatter.set_y(&[4.0, 5.1, 4.0, 2.0, 1.0, 1.8]);
+  scatter.set_yerr_asym(
+    &[0.3, 0.2, 0.5, 0.4, 0.2, 0.3],
+    &[0.6, 0.4, 0.3, 0.2, 0.5, 0.4],
+  );
+  scatter.set_xerr(&[0.2; 6]);
+
+  let ax = figure.nth(0).unwrap();
+  ax.add(Box::new(curve));
+  ax.add(Box::new(scatter));
+  ax.set_strategy(painter::ScaleStrategy::Stretch);
+
+  figure.show();
+}
@@ -10,7 +10,6 @@ fn main() {
   let y = [0.0, 1., 2., 3., 4., 5.];
 
   let y1: Vec<f32> = y.iter().map(|&v| v + 1.).collect();
-  let y2: Vec<f32> = y.iter().map(|&v| v * 2.).collect();
 
   let mut his = Area::new("test".to_string(), primitive::Config::default());
   his.set_data(&x, &y);
@@ -22,11 +21,19 @@ fn main() {
   his2.change_area_type(AreaType::Line);
   his2.set_data(&x, &[1., 4., 3., 7., 2., 7.]);
 
+  // 两条曲线之间的填充，只填充 upper 高于 lower 的部分
+  let mut band = Area::new("band".to_string(), primitive::Config::default());
+  band.change_area_type(AreaType::Line);
+  band.set_data(&x, &[2., 5., 1., 6., 3., 4., 2.]);
+  band.set_lower(&[1., 3., 3., 2., 5., 2., 3.]);
+  band.set_where(|upper, lower| upper > lower);
+
   let ax = figure.nth(0).unwrap();
 
   ax.add(Box::new(his));
   ax.add(Box::new(his1));
   ax.add(Box::new(his2));
+  ax.add(Box::new(band));
   ax.set_strategy(painter::ScaleStrategy::Stretch);
 
   figure.show();
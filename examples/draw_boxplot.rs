@@ -0,0 +1,28 @@
+use painter::{
+  Config, Figure,
+  primitive::{BoxPlot, Whisker},
+};
+
+fn main() {
+  let mut figure = Figure::new(Config::default());
+
+  let mut boxplot = BoxPlot::new("latency".to_string());
+
+  // 每组原始样本，最后一个值作为离群点
+  let api: [f32; 10] = [12., 15., 14., 13., 18., 16., 15., 14., 13., 35.];
+  let db: [f32; 10] = [22., 25., 21., 28., 24., 26., 23., 27., 25., 24.];
+  let cache: [f32; 10] = [2., 3., 2.5, 3.5, 2.8, 3.1, 2.2, 2.9, 3.3, 9.];
+
+  boxplot.add_group("api".to_string(), &api);
+  boxplot.add_group("db".to_string(), &db);
+  boxplot.add_group("cache".to_string(), &cache);
+  boxplot.set_whisker(Whisker::Tukey(1.5));
+  boxplot.set_notch(true);
+  boxplot.set_show_mean(true);
+
+  let ax = figure.nth(0).unwrap();
+  ax.add(Box::new(boxplot));
+  ax.set_strategy(painter::ScaleStrategy::Stretch);
+
+  figure.show();
+}
@@ -0,0 +1,50 @@
+use painter::{
+  Config, Figure,
+  primitive::{self, Candlestick},
+};
+
+fn main() {
+  let mut figure = Figure::new(Config::default());
+
+  // 30 个交易日，周末休市，x 为自然日
+  let mut x = Vec::new();
+  let mut labels = Vec::new();
+  let (mut open, mut high, mut low, mut close, mut volume) =
+    (Vec::new(), Vec::new(), Vec::new(), Vec::new(), Vec::new());
+  let mut price = 100.0_f32;
+  let mut day = 0;
+  while x.len() < 30 {
+    day += 1;
+    if day % 7 == 5 || day % 7 == 6 {
+      continue;
+    }
+    let change = ((day as f32) * 1.7).sin() * 3.0 + ((day as f32) * 0.3).cos();
+    let o = price;
+    let c = price + change;
+    x.push(day as f32);
+    labels.push(if day <= 31 {
+      format!("03-{:02}", day)
+    } else {
+      format!("04-{:02}", day - 31)
+    });
+    open.push(o);
+    close.push(c);
+    high.push(o.max(c) + 1.0 + (day % 3) as f32);
+    low.push(o.min(c) - 1.0 - (day % 2) as f32);
+    volume.push(1000.0 + change.abs() * 400.0);
+    price = c;
+  }
+  let labels: Vec<&str> = labels.iter().map(|s| s.as_str()).collect();
+
+  let mut candle = Candlestick::new("price".to_string(), primitive::Config::default());
+  candle.set_data(&x, &open, &high, &low, &close);
+  candle.set_volume(&volume);
+  candle.set_labels(&labels);
+  candle.set_hollow(true);
+
+  let ax = figure.nth(0).unwrap();
+  ax.add(Box::new(candle));
+  ax.set_strategy(painter::ScaleStrategy::Stretch);
+
+  figure.show();
+}
@@ -0,0 +1,31 @@
+use painter::{
+  Colormap, Config, Figure,
+  primitive::{self, Contour, ContourFilled},
+  utils,
+};
+
+fn main() {
+  let mut figure = Figure::new(Config::default());
+
+  let x = utils::linspace(-3., 3., 60);
+  let y = utils::linspace(-2., 2., 40);
+  // 两个高斯峰叠加
+  let f = |x: f32, y: f32| {
+    (-((x - 1.).powi(2) + y.powi(2))).exp() - 0.8 * (-((x + 1.).powi(2) + (y - 0.5).powi(2))).exp()
+  };
+
+  let mut filled = ContourFilled::new("field".to_string(), primitive::Config::default());
+  filled.set_fn(&x, &y, f);
+  filled.set_colormap(Colormap::Coolwarm);
+
+  let mut lines = Contour::new("levels".to_string(), primitive::Config::default());
+  lines.set_fn(&x, &y, f);
+  lines.set_colormap(Colormap::Gray);
+  lines.set_inline_labels(true);
+
+  let ax = figure.nth(0).unwrap();
+  ax.add(Box::new(filled));
+  ax.add(Box::new(lines));
+
+  figure.show();
+}
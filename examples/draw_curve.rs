@@ -1,6 +1,8 @@
+use std::f32::consts::{PI, TAU};
+
 use painter::{
   Config, Figure,
-  primitive::{self, Curve},
+  primitive::{self, Curve, LineStyle},
   utils,
 };
 
@@ -11,19 +13,28 @@ fn main() {
 
   // 线 2: 参数方程
   let mut curve2 = Curve::new("Circle".to_string(), primitive::Config::default());
-  let t = utils::linspace(0.0, 6.28, 100);
+  let t = utils::linspace(0.0, TAU, 100);
   curve2.set_parametric(&t, |v| v.cos(), |v| v.sin());
   ax.add(Box::new(curve2));
   // 线 1: 函数生成
   let mut curve1 = Curve::new("Sine".to_string(), primitive::Config::default());
-  let x = utils::linspace(3.14, 6.28, 100);
+  let x = utils::linspace(PI, TAU, 100);
   curve1.set_fn(&x, |v| 3. * v.sin());
   ax.add(Box::new(curve1));
 
   // 线 3: 直接喂数据
-  let mut curve3 = Curve::new("Data".to_string(), primitive::Config::default());
+  let mut curve3 = Curve::new(
+    "Data".to_string(),
+    primitive::Config {
+      line_style: LineStyle::Dashed,
+      alpha: 0.8,
+      ..Default::default()
+    },
+  );
   curve3.add_data(&[0.0, 2.0, 4.0], &[0.5, 0.8, 0.2]);
   ax.add(Box::new(curve3));
+  ax.set_major_grid(Some(LineStyle::Dashed));
+  ax.set_minor_grid(Some(LineStyle::Dotted));
 
   figure.show();
 }
@@ -0,0 +1,30 @@
+use painter::{
+  Colormap, Config, Figure,
+  primitive::{self, Image, Interpolation, Origin},
+};
+
+fn main() {
+  let mut figure = Figure::new(Config::default());
+
+  // 32x24 的标量矩阵，按 colormap 着色
+  let z: Vec<Vec<f32>> = (0..24)
+    .map(|j| {
+      (0..32)
+        .map(|i| ((i as f32) * 0.3).sin() * ((j as f32) * 0.25).cos())
+        .collect()
+    })
+    .collect();
+
+  let mut image = Image::new("field".to_string(), primitive::Config::default());
+  image.set_data(&z);
+  image.set_colormap(Colormap::Inferno);
+  image.set_origin(Origin::Lower);
+  image.set_interpolation(Interpolation::Bilinear);
+
+  let ax = figure.nth(0).unwrap();
+  ax.add(Box::new(image));
+  // Fit（默认）下每个像素保持正方形
+  ax.set_strategy(painter::ScaleStrategy::Fit);
+
+  figure.show();
+}
@@ -0,0 +1,47 @@
+use painter::{
+  Config, Figure, Projection, ThetaDirection, ThetaZero,
+  primitive::{self, Radar},
+};
+
+fn main() {
+  let mut figure = Figure::new(Config::default());
+  figure.add_subplot((1, 2));
+
+  let metrics = ["latency", "throughput", "uptime", "cost", "support"];
+
+  // 相同量程：环上标出数值
+  let ax1 = figure.nth(0).unwrap();
+  ax1.set_projection(Projection::Polar);
+  ax1.set_theta_zero(ThetaZero::North);
+  ax1.set_theta_direction(ThetaDirection::Clockwise);
+  for (name, scores) in [
+    ("service a", [4.0, 3.0, 4.5, 2.0, 3.5]),
+    ("service b", [2.5, 4.5, 3.0, 4.0, 2.0]),
+  ] {
+    let mut radar = Radar::new(name.to_string(), primitive::Config::default());
+    radar.set_labels(&metrics);
+    radar.set_data(&scores);
+    radar.set_ranges(&[(0.0, 5.0); 5]);
+    ax1.add(Box::new(radar));
+  }
+
+  // 每个方向各自的量程
+  let ax2 = figure.nth(1).unwrap();
+  ax2.set_projection(Projection::Polar);
+  ax2.set_theta_zero(ThetaZero::North);
+  ax2.set_theta_direction(ThetaDirection::Clockwise);
+  let mut raw = Radar::new("service c".to_string(), primitive::Config::default());
+  raw.set_labels(&metrics);
+  raw.set_data(&[120.0, 900.0, 0.99, 30.0, 7.0]);
+  raw.set_ranges(&[
+    (0.0, 200.0),
+    (0.0, 1000.0),
+    (0.9, 1.0),
+    (0.0, 50.0),
+    (0.0, 10.0),
+  ]);
+  raw.set_fill_alpha(None);
+  ax2.add(Box::new(raw));
+
+  figure.show();
+}
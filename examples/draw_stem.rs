@@ -0,0 +1,36 @@
+use painter::{
+  Config, Figure,
+  primitive::{self, Marker, Stem},
+};
+
+fn main() {
+  let mut figure = Figure::new(Config::default());
+
+  // 离散 sinc 序列 h[n]
+  let h: Vec<f32> = (-10..=10)
+    .map(|n| {
+      if n == 0 {
+        1.0
+      } else {
+        let x = n as f32 * 0.4 * std::f32::consts::PI;
+        x.sin() / x
+      }
+    })
+    .collect();
+  let n: Vec<f32> = (-10..=10).map(|n| n as f32).collect();
+
+  let mut stem = Stem::new("h[n]".to_string(), primitive::Config::default());
+  stem.set_data(&n, &h);
+
+  let mut shifted = Stem::new("x[n]".to_string(), primitive::Config::default());
+  shifted.set_data_norm(&[0.5, 0.8, 0.3, -0.2, 0.6]);
+  shifted.set_baseline(-0.4);
+  shifted.set_marker(Marker::Diamond, 5.0);
+
+  let ax = figure.nth(0).unwrap();
+  ax.add(Box::new(stem));
+  ax.add(Box::new(shifted));
+  ax.set_strategy(painter::ScaleStrategy::Stretch);
+
+  figure.show();
+}
@@ -0,0 +1,31 @@
+use painter::{
+  Config, Figure,
+  primitive::{Bandwidth, Violin, ViolinInner},
+};
+
+fn main() {
+  let mut figure = Figure::new(Config::default());
+
+  let mut violin = Violin::new("latency".to_string());
+  violin.set_labels(&["api", "db", "cache"]);
+
+  // 两个版本的延迟分布，同一槽位并排显示
+  let v1_api: [f32; 10] = [12., 15., 14., 13., 18., 16., 15., 14., 13., 20.];
+  let v1_db: [f32; 10] = [22., 25., 21., 28., 24., 26., 23., 27., 25., 24.];
+  let v1_cache: [f32; 10] = [2., 3., 2.5, 3.5, 2.8, 3.1, 2.2, 2.9, 3.3, 6.];
+  violin.add_series(&[&v1_api, &v1_db, &v1_cache]);
+
+  let v2_api: [f32; 8] = [10., 11., 12., 11., 10., 13., 12., 11.];
+  let v2_db: [f32; 8] = [18., 19., 20., 30., 31., 19., 29., 20.];
+  let v2_cache: [f32; 8] = [2., 2.1, 2.3, 2.2, 2.4, 2.0, 2.2, 2.1];
+  violin.add_series(&[&v2_api, &v2_db, &v2_cache]);
+
+  violin.set_bandwidth(Bandwidth::Silverman);
+  violin.set_inner(ViolinInner::Quartiles);
+
+  let ax = figure.nth(0).unwrap();
+  ax.add(Box::new(violin));
+  ax.set_strategy(painter::ScaleStrategy::Stretch);
+
+  figure.show();
+}
@@ -0,0 +1,37 @@
+use painter::{
+  Config, Figure, GridSpec,
+  primitive::{self, Bins, Curve, Histrogram, Scatter},
+  utils,
+};
+
+fn main() {
+  let mut figure = Figure::new(Config::default());
+
+  // 左侧大图占两行两列，右侧两个小图各占一行
+  let mut grid = GridSpec::new(2, 3);
+  grid.set_width_ratios(&[2.0, 2.0, 1.5]);
+  grid.set_spacing(10.0, 10.0);
+  figure.set_gridspec(grid);
+
+  let main = figure.add_subplot_span(0..2, 0..2);
+  let top = figure.add_subplot_span(0..1, 2..3);
+  let bottom = figure.add_subplot_span(1..2, 2..3);
+
+  let x = utils::linspace(0.0, 10.0, 200);
+  let mut wave = Curve::new("wave".to_string(), primitive::Config::default());
+  wave.set_fn(&x, |v| v.sin() * (-v / 5.0).exp());
+  figure.axis(main).add(Box::new(wave));
+
+  let samples: Vec<f32> = (0..300)
+    .map(|i| ((i as f32 * 0.37).sin() * 3.0).round())
+    .collect();
+  let hist = Histrogram::from_samples("samples".to_string(), &samples, Bins::Count(7));
+  figure.axis(top).add(Box::new(hist));
+
+  let mut points = Scatter::new("points".to_string(), primitive::Config::default());
+  points.set_x(&[1.0, 2.0, 3.0, 4.0, 5.0]);
+  points.set_y(&[2.0, 3.5, 3.0, 5.0, 4.5]);
+  figure.axis(bottom).add(Box::new(points));
+
+  figure.show();
+}
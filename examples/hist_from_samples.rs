@@ -0,0 +1,45 @@
+use painter::{
+  Config, Figure,
+  primitive::{Bins, HistStyle, Histrogram},
+};
+
+/// 简单的线性同余生成器 + Box-Muller，生成正态分布样本
+fn normal_samples(n: usize, mean: f32, std: f32, mut seed: u32) -> Vec<f32> {
+  let mut uniform = move || {
+    seed = seed.wrapping_mul(1664525).wrapping_add(1013904223);
+    (seed >> 8) as f32 / (1u32 << 24) as f32
+  };
+  (0..n)
+    .map(|_| {
+      let (u1, u2) = (uniform().max(1e-6), uniform());
+      mean + std * (-2.0 * u1.ln()).sqrt() * (2.0 * std::f32::consts::PI * u2).cos()
+    })
+    .collect()
+}
+
+fn main() {
+  let mut figure = Figure::new(Config::default());
+  figure.add_subplot((1, 2));
+
+  let a = normal_samples(500, 0.0, 1.0, 1);
+  let b = normal_samples(500, 1.5, 0.7, 2);
+
+  // 左图：两个重叠分布，density + 填充阶梯
+  let mut his = Histrogram::from_samples("a".to_string(), &a, Bins::FreedmanDiaconis);
+  his.add_samples(&b);
+  his.set_density(true);
+  his.set_style(HistStyle::StepFilled);
+  let ax = figure.nth(0).unwrap();
+  ax.add(Box::new(his));
+  ax.set_strategy(painter::ScaleStrategy::Stretch);
+
+  // 右图：累积直方图
+  let mut cum = Histrogram::from_samples("cumulative".to_string(), &a, Bins::Sturges);
+  cum.set_cumulative(true);
+  cum.set_density(true);
+  let ax = figure.nth(1).unwrap();
+  ax.add(Box::new(cum));
+  ax.set_strategy(painter::ScaleStrategy::Stretch);
+
+  figure.show();
+}
@@ -0,0 +1,31 @@
+use painter::{
+  Config, Figure, ScaleStrategy,
+  primitive::{self, Curve},
+  utils,
+};
+
+fn main() {
+  let mut figure = Figure::new(Config::default());
+  let main = figure.add_subplot((1, 1))[0];
+
+  let signal = |t: f32| t.sin() + 0.1 * (t * 20.0).sin();
+  let t = utils::linspace(0.0, 10.0, 600);
+  let mut curve = Curve::new("signal".to_string(), primitive::Config::default());
+  curve.set_fn(&t, signal);
+  let axis = figure.axis(main);
+  axis.set_strategy(ScaleStrategy::Stretch);
+  axis.add(Box::new(curve));
+
+  // 右上角的放大图：只画 1..2 区间的细节
+  let inset = figure.add_axes([0.58, 0.58, 0.36, 0.3]);
+  let t = utils::linspace(1.0, 2.0, 200);
+  let mut detail = Curve::new("detail".to_string(), primitive::Config::default());
+  detail.set_fn(&t, signal);
+  let axis = figure.axis(inset);
+  axis.set_strategy(ScaleStrategy::Stretch);
+  axis.set_tight_layout(true);
+  axis.add(Box::new(detail));
+  figure.indicate_inset_zoom(main, inset);
+
+  figure.show();
+}
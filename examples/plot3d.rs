@@ -0,0 +1,47 @@
+use painter::{
+  Colormap, Config, Figure, Projection,
+  primitive::{self, Line3D, Scatter3D, Surface, SurfaceStyle},
+  utils,
+};
+
+fn main() {
+  let mut figure = Figure::new(Config::default());
+  figure.add_subplot((1, 2));
+
+  // 螺旋线和散点，拖动鼠标旋转视角
+  let t = utils::linspace(0.0, 20.0, 400);
+  let mut helix = Line3D::new("helix".to_string(), primitive::Config::default());
+  helix.set_parametric(&t, |t| t.cos(), |t| t.sin(), |t| t / 4.0);
+
+  let n: Vec<f32> = (0..60).map(|i| i as f32).collect();
+  let x: Vec<f32> = n.iter().map(|i| (i * 0.7).sin()).collect();
+  let y: Vec<f32> = n.iter().map(|i| (i * 1.3).cos()).collect();
+  let z: Vec<f32> = n.iter().map(|i| i / 12.0).collect();
+  let mut points = Scatter3D::new("points".to_string(), primitive::Config::default());
+  points.set_data(&x, &y, &z);
+
+  let ax1 = figure.nth(0).unwrap();
+  ax1.set_projection(Projection::ThreeD);
+  ax1.add(Box::new(helix));
+  ax1.add(Box::new(points));
+
+  // 高斯曲面，按高度着色，叠加一层线框
+  let grid = utils::linspace(-3.0, 3.0, 30);
+  let bump = |x: f32, y: f32| (-(x * x + y * y) / 3.0).exp() * 2.0;
+  let mut surface = Surface::new("bump".to_string(), primitive::Config::default());
+  surface.set_fn(&grid, &grid, bump);
+  surface.set_colormap(Some(Colormap::Viridis));
+
+  let ax2 = figure.nth(1).unwrap();
+  ax2.set_projection(Projection::ThreeD);
+  ax2.set_view(25.0, -50.0);
+  ax2.add(Box::new(surface));
+
+  let coarse = utils::linspace(-3.0, 3.0, 12);
+  let mut wire = Surface::new("wire".to_string(), primitive::Config::default());
+  wire.set_fn(&coarse, &coarse, |x, y| bump(x, y) + 0.5);
+  wire.set_style(SurfaceStyle::Wireframe);
+  ax2.add(Box::new(wire));
+
+  figure.show();
+}
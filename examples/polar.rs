@@ -0,0 +1,40 @@
+use std::f32::consts::TAU;
+
+use painter::{
+  AngleUnit, Config, Figure, Projection, ThetaDirection, ThetaZero,
+  primitive::{self, Bins, Curve, Histrogram},
+  utils,
+};
+
+fn main() {
+  let mut figure = Figure::new(Config::default());
+  figure.add_subplot((1, 2));
+
+  // 天线方向图：r = |cos(2θ)|
+  let theta = utils::linspace(0.0, TAU, 361);
+  let mut pattern = Curve::new("gain".to_string(), primitive::Config::default());
+  pattern.set_fn(&theta, |t| 10.0 * (2.0 * t).cos().abs() + 2.0);
+
+  let ax1 = figure.nth(0).unwrap();
+  ax1.set_projection(Projection::Polar);
+  ax1.set_angle_unit(AngleUnit::Radians);
+  ax1.add(Box::new(pattern));
+
+  // 风玫瑰图：风向样本按 16 个方位分箱，正北为 0，顺时针
+  let directions: Vec<f32> = (0..400)
+    .map(|i| ((i as f32 * 0.61).sin() * 1.2 + 1.0 + (i % 7) as f32 * 0.3).rem_euclid(TAU))
+    .collect();
+  let rose = Histrogram::from_samples(
+    "wind".to_string(),
+    &directions,
+    Bins::Edges(utils::linspace(0.0, TAU, 17)),
+  );
+
+  let ax2 = figure.nth(1).unwrap();
+  ax2.set_projection(Projection::Polar);
+  ax2.set_theta_zero(ThetaZero::North);
+  ax2.set_theta_direction(ThetaDirection::Clockwise);
+  ax2.add(Box::new(rose));
+
+  figure.show();
+}
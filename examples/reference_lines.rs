@@ -0,0 +1,59 @@
+use painter::{
+  Config, Figure, ScaleStrategy,
+  primitive::{self, Curve, LineStyle, RefLine, RefSpan},
+  utils,
+};
+
+fn main() {
+  let mut figure = Figure::new(Config::default());
+  let axis = figure.add_subplot((1, 1))[0];
+  let axis = figure.axis(axis);
+  axis.set_strategy(ScaleStrategy::Stretch);
+
+  // 一天内的请求延迟 (ms)
+  let t = utils::linspace(0.0, 24.0, 300);
+  let mut latency = Curve::new("latency".to_string(), primitive::Config::default());
+  latency.set_fn(&t, |h| {
+    120.0 + 50.0 * (h / 3.0).sin() + 8.0 * (h * 2.0).cos()
+  });
+  axis.add(Box::new(latency));
+
+  // 故障窗口：垂直阴影带，不影响自动范围
+  let mut incident = RefSpan::new(
+    "incident".to_string(),
+    primitive::Config {
+      color: [230, 90, 90, 255],
+      ..Default::default()
+    },
+  );
+  incident.set_vertical(8.0, 11.0);
+  incident.set_label(Some("incident"));
+  axis.add(Box::new(incident));
+
+  // SLO 阈值：虚线水平线
+  let mut slo = RefLine::new(
+    "slo".to_string(),
+    primitive::Config {
+      color: [250, 180, 80, 255],
+      line_style: LineStyle::Dashed,
+      stroke_width: 1.5,
+      ..Default::default()
+    },
+  );
+  slo.set_horizontal(170.0);
+  slo.set_label(Some("SLO 170 ms"));
+  axis.add(Box::new(slo));
+
+  let mut deploy = RefLine::new(
+    "deploy".to_string(),
+    primitive::Config {
+      alpha: 0.6,
+      ..Default::default()
+    },
+  );
+  deploy.set_vertical(18.0);
+  deploy.set_label(Some("deploy"));
+  axis.add(Box::new(deploy));
+
+  figure.show();
+}
@@ -0,0 +1,31 @@
+use painter::{
+  Config, Figure, ScaleStrategy,
+  primitive::{self, Curve},
+  utils,
+};
+
+fn main() {
+  let mut figure = Figure::new(Config::default());
+  // 三个子图共享同一时间轴：拖动平移、滚轮缩放时一起联动
+  let handles = figure.add_subplot((3, 1));
+
+  let t = utils::linspace(0.0, 24.0, 300);
+  let day = std::f32::consts::TAU / 24.0;
+  let temperature: Vec<f32> = t.iter().map(|t| 18.0 + 6.0 * (t * day).sin()).collect();
+  let humidity: Vec<f32> = t.iter().map(|t| 60.0 - 15.0 * (t / 12.0).cos()).collect();
+  let load: Vec<f32> = t.iter().map(|t| (t * 0.8).sin().abs() * 4.0).collect();
+  for (&handle, (name, y)) in handles.iter().zip([
+    ("temperature", temperature),
+    ("humidity", humidity),
+    ("load", load),
+  ]) {
+    let mut curve = Curve::new(name.to_string(), primitive::Config::default());
+    curve.set_data(&t, &y);
+    let axis = figure.axis(handle);
+    axis.set_strategy(ScaleStrategy::Stretch);
+    axis.add(Box::new(curve));
+  }
+  figure.sharex(&handles);
+
+  figure.show();
+}
@@ -0,0 +1,28 @@
+use painter::{
+  Config, Figure, ScaleStrategy,
+  primitive::{self, Curve},
+  utils,
+};
+
+fn main() {
+  let mut figure = Figure::new(Config::default());
+  let handles = figure.add_subplot((2, 2));
+  // 按实际文字尺寸计算边距：长刻度标签、轴标签和标题都不会被裁掉
+  figure.set_tight_layout(true);
+
+  let t = utils::linspace(0.0, 10.0, 200);
+  for (i, &handle) in handles.iter().enumerate() {
+    let scale = 10_f32.powi(i as i32 * 2);
+    let mut curve = Curve::new(format!("series {}", i), primitive::Config::default());
+    curve.set_fn(&t, |v| scale * (1.0 + (v + i as f32).sin() / 2.0));
+
+    let axis = figure.axis(handle);
+    axis.set_strategy(ScaleStrategy::Stretch);
+    axis.set_title(&format!("scale 1e{}", i * 2));
+    axis.set_x_label("time (s)");
+    axis.set_y_label("value");
+    axis.add(Box::new(curve));
+  }
+
+  figure.show();
+}
@@ -0,0 +1,36 @@
+use painter::{
+  Config, Figure, ScaleStrategy,
+  primitive::{self, Curve, Stem},
+  utils,
+};
+
+fn main() {
+  let mut figure = Figure::new(Config::default());
+
+  // 同一个绘图区：左轴温度，右轴降雨量
+  let months = utils::linspace(1.0, 12.0, 12);
+  let mut temperature = Curve::new("temperature".to_string(), primitive::Config::default());
+  temperature.set_fn(&months, |m| {
+    12.0 - 10.0 * ((m - 1.0) / 12.0 * std::f32::consts::TAU).cos()
+  });
+
+  let mut rain = Stem::new("rain".to_string(), primitive::Config::default());
+  rain.set_data(
+    &months,
+    &[
+      45.0, 40.0, 50.0, 60.0, 75.0, 90.0, 110.0, 105.0, 80.0, 65.0, 55.0, 50.0,
+    ],
+  );
+
+  let ax = figure.nth(0).unwrap();
+  ax.set_strategy(ScaleStrategy::Stretch);
+  ax.set_x_label("month");
+  ax.set_y_label("temperature (C)");
+  ax.add(Box::new(temperature));
+
+  let right = ax.twinx();
+  right.set_y_label("rain (mm)");
+  right.add(Box::new(rain));
+
+  figure.show();
+}
@@ -13,7 +13,7 @@ fn main() {
 
   let mut c = Curve::new("y = sinx".to_string(), primitive::Config::default());
   let mut c1 = Curve::new("test".to_string(), primitive::Config::default());
-  let t = utils::linspace(0.0, 6.28, 100);
+  let t = utils::linspace(0.0, f32::consts::TAU, 100);
   let y = utils::sin(&t);
   c.set_data(&t, &y);
 
@@ -0,0 +1,31 @@
+use painter::{
+  Colormap, Config, Figure,
+  primitive::{self, Quiver, StreamPlot},
+  utils,
+};
+
+fn main() {
+  let mut figure = Figure::new(Config::default());
+  figure.add_subplot((1, 2));
+
+  // 绕原点的涡旋加上一个向右的均匀流
+  let field = |x: f32, y: f32| (1.0 - y, x);
+
+  let x = utils::linspace(-2., 2., 15);
+  let y = utils::linspace(-2., 2., 15);
+  let mut quiver = Quiver::new("velocity".to_string(), primitive::Config::default());
+  quiver.set_fn(&x, &y, field);
+  quiver.set_color_by_magnitude(Some(Colormap::Viridis));
+  quiver.set_key(2.0, "|v| = 2");
+
+  let x = utils::linspace(-2., 2., 40);
+  let y = utils::linspace(-2., 2., 40);
+  let mut stream = StreamPlot::new("stream".to_string(), primitive::Config::default());
+  stream.set_fn(&x, &y, field);
+  stream.set_density(0.8);
+
+  figure.nth(0).unwrap().add(Box::new(quiver));
+  figure.nth(1).unwrap().add(Box::new(stream));
+
+  figure.show();
+}
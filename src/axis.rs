@@ -1,6 +1,19 @@
-use tiny_skia::{Color, Paint, PathBuilder, Pixmap, Rect, Stroke, Transform};
+use tiny_skia::{
+  Color, FillRule, Mask, Paint, Path, PathBuilder, Pixmap, Point, Rect, Stroke, StrokeDash,
+  Transform,
+};
 
-use crate::{color, drawable::Drawable, text_render::TextRender};
+use crate::{
+  color,
+  drawable::{Bound, Bound3D, Drawable},
+  polar::{self, AngleUnit, Polar, ThetaDirection, ThetaZero},
+  primitive::LineStyle,
+  text_render::{HAlign, TextRender, TextStyle, VAlign},
+  view3d::View3D,
+};
+
+/// `(position, label)` of each tick
+type TickLabels = Vec<(f32, String)>;
 
 pub struct Axis {
   x: f32,
@@ -8,17 +21,35 @@ pub struct Axis {
   viewport: Rect,
   color_index: usize,
   drawables: Vec<Box<dyn Drawable>>,
+  /// secondary axes from `twinx` and `twiny`
+  twins: Vec<Axis>,
+  /// plot area `(ui transform, size)` given by the parent of a twin
+  frame: Option<(Transform, (f32, f32))>,
+  /// margins measured by the tight layout, the fixed margin is used without it
+  margins: Option<Margins>,
+  /// tight margins lined up by the figure with the axes of the same grid rows and columns
+  grid_margins: Option<Margins>,
 
   config: Config,
 }
 
 impl Axis {
+  const TITLE_SIZE: f32 = 14.0;
+  /// pixels between the title and the text below it
+  const TITLE_GAP: f32 = 6.0;
+  /// pixels kept free around the measured text in the tight layout
+  const TIGHT_PAD: f32 = 8.0;
+
   pub(crate) fn new(x: f32, y: f32, size: (f32, f32)) -> Self {
     Self {
       x,
       y,
       viewport: Rect::from_xywh(0., 0., size.0, size.1).unwrap(),
       drawables: Vec::new(),
+      twins: Vec::new(),
+      frame: None,
+      margins: None,
+      grid_margins: None,
       color_index: 0,
       config: Config::default(),
     }
@@ -27,33 +58,20 @@ impl Axis {
     self.x = axis.0;
     self.y = axis.1;
     self.viewport = Rect::from_xywh(0., 0., size.0, size.1).unwrap();
+    for twin in &mut self.twins {
+      twin.change_veiwport(axis, size);
+    }
   }
 
   /// design to render axis with grid lines
-  fn render_axis(&self, pixmap: &mut Pixmap, ui_ts: &Transform, tr: &TextRender) {
-    let width = self.viewport.width();
-    let height = self.viewport.height();
-    let margin = (width * 0.1).min(50.0);
-
+  fn render_axis(&self, pixmap: &mut Pixmap, ui_ts: &Transform, size: (f32, f32), tr: &TextRender) {
     let (x_min, x_max) = self.config.x_limit.unwrap_or((-1.0, 1.0));
     let (y_min, y_max) = self.config.y_limit.unwrap_or((-1.0, 1.0));
 
     let x_range = (x_max - x_min).max(1e-6);
     let y_range = (y_max - y_min).max(1e-6);
 
-    let plot_w = width - 2.0 * margin;
-    let plot_h = height - 2.0 * margin;
-
-    let (scale_x, scale_y) = match self.config.strategy {
-      ScaleStrategy::Stretch => (plot_w / x_range, plot_h / y_range),
-      ScaleStrategy::Fit => {
-        let s = (plot_w / x_range).min(plot_h / y_range);
-        (s, s)
-      }
-    };
-
-    let actual_w = x_range * scale_x;
-    let actual_h = y_range * scale_y;
+    let (actual_w, actual_h) = size;
 
     // 计算 0 点在画布上的像素位置
     let origin_x = if x_min >= 0.0 {
@@ -82,19 +100,110 @@ impl Axis {
       tr,
       (x_interval, xn),
       (y_interval, yn),
-      actual_w,
-      actual_h,
+      (actual_w, actual_h),
     );
+    self.draw_axis_labels(pixmap, ui_ts, tr, (actual_w, actual_h));
+    self.draw_title(pixmap, ui_ts, tr, (actual_w, actual_h));
 
-    if x_min <= 0.0 && x_max >= 0.0 {
-      self.draw_axis_y(pixmap, ui_ts, actual_h, origin_x);
+    match self.config.twin {
+      // a twin draws its own side of the plot area instead of the zero lines
+      Some(twin) => {
+        let mut pb = PathBuilder::new();
+        match twin {
+          Twin::X => {
+            pb.move_to(actual_w, 0.0);
+            pb.line_to(actual_w, -actual_h);
+          }
+          Twin::Y => {
+            pb.move_to(0.0, -actual_h);
+            pb.line_to(actual_w, -actual_h);
+          }
+        }
+        self.stroke_path(pixmap, pb, ui_ts, 1.5, color::get_fg(), None);
+      }
+      None => {
+        if x_min <= 0.0 && x_max >= 0.0 {
+          self.draw_axis_y(pixmap, ui_ts, actual_h, origin_x);
+        }
+        if y_min <= 0.0 && y_max >= 0.0 {
+          self.draw_axis_x(pixmap, ui_ts, actual_w, origin_y);
+        }
+      }
     }
-    if y_min <= 0.0 && y_max >= 0.0 {
-      self.draw_axis_x(pixmap, ui_ts, actual_w, origin_y);
+  }
+  /// x label under the plot area (above it for a `twiny`), y label over the left side of the
+  /// plot area (the right side for a `twinx`)
+  fn draw_axis_labels(
+    &self, pixmap: &mut Pixmap, ui_ts: &Transform, tr: &TextRender, size: (f32, f32),
+  ) {
+    let (w, h) = size;
+    let font_size = 12.0;
+    let [r, g, b, a] = color::get_fg();
+    let text_color = Color::from_rgba8(r, g, b, a);
+    if let Some(label) = &self.config.x_label {
+      let text_w = tr.measure(label, font_size).0;
+      let y = match self.config.twin {
+        Some(Twin::Y) => ui_ts.ty - h - 28.0 - font_size,
+        _ => ui_ts.ty + 28.0,
+      };
+      tr.draw(
+        pixmap,
+        label,
+        ui_ts.tx + (w - text_w) / 2.0,
+        y,
+        font_size,
+        text_color,
+      );
+    }
+    if let Some(label) = &self.config.y_label {
+      // read upward, beside the tick labels
+      let text_h = tr.measure(label, font_size).1;
+      let (x_info, y_info) = self.tick_info();
+      let gap = Self::y_label_gap(&self.visible_tick_labels(x_info, y_info).1, tr);
+      let (x, v_align) = match self.config.twin {
+        Some(Twin::X) => (
+          (ui_ts.tx + w + gap).min(self.x + self.viewport.width() - text_h),
+          VAlign::Top,
+        ),
+        _ => ((ui_ts.tx - gap).max(self.x + text_h), VAlign::Bottom),
+      };
+      let style = TextStyle {
+        size: font_size,
+        color: text_color,
+        h_align: HAlign::Center,
+        v_align,
+        rotation: 90.0,
+      };
+      tr.draw_styled(pixmap, label, x, ui_ts.ty - h / 2.0, &style, None);
     }
-    // println!("{} {}", x_interval, y_interval);
+  }
+  /// pixels between the plot area and a y label, past the widest tick label
+  fn y_label_gap(tick_labels: &[(f32, String)], tr: &TextRender) -> f32 {
+    let widest = tick_labels
+      .iter()
+      .map(|(_, l)| tr.measure(l, 12.0).0)
+      .fold(0.0, f32::max);
+    if widest > 0.0 { widest + 14.0 } else { 6.0 }
   }
   fn draw_grid(&self, pixmap: &mut Pixmap, ts: &Transform, x_int: f32, y_int: f32, w: f32, h: f32) {
+    // minor grid first, so the major lines stay on top
+    if let Some(style) = &self.config.minor_grid {
+      let pb = self.grid_path(
+        Self::minor_interval(x_int),
+        Self::minor_interval(y_int),
+        w,
+        h,
+      );
+      let [r, g, b, a] = color::get_gray();
+      self.stroke_path(pixmap, pb, ts, 0.5, [r, g, b, a / 2], style.dash(0.5));
+    }
+    if let Some(style) = &self.config.major_grid {
+      let pb = self.grid_path(x_int, y_int, w, h);
+      self.stroke_path(pixmap, pb, ts, 1., color::get_gray(), style.dash(1.));
+    }
+  }
+  /// build the grid lines for the given intervals in ui coordinates
+  fn grid_path(&self, x_int: f32, y_int: f32, w: f32, h: f32) -> PathBuilder {
     let mut pb = PathBuilder::new();
     let (x_min, x_max) = self.config.x_limit.unwrap_or((0.0, 10.0));
     let (y_min, y_max) = self.config.y_limit.unwrap_or((0.0, 10.0));
@@ -127,66 +236,212 @@ impl Axis {
       y_val += y_int;
     }
 
-    self.stroke_path(pixmap, pb, ts, 1., color::get_gray());
+    pb
+  }
+  /// split a "nice" major interval: 2 -> 4 parts, 1 and 5 -> 5 parts
+  fn minor_interval(major: f32) -> f32 {
+    let mantissa = major / 10_f32.powf(major.log10().floor());
+    if (mantissa - 2.0).abs() < 0.1 {
+      major / 4.0
+    } else {
+      major / 5.0
+    }
   }
   fn draw_ticks(
     &self, pixmap: &mut Pixmap, ui_ts: &Transform, tr: &TextRender, x_info: (f32, usize),
-    y_info: (f32, usize), w: f32, h: f32,
+    y_info: (f32, usize), size: (f32, f32),
   ) {
+    let (w, h) = size;
     let (x_min, x_max) = self.config.x_limit.unwrap_or((0.0, 1.0));
     let (y_min, y_max) = self.config.y_limit.unwrap_or((0.0, 1.0));
     let x_range = (x_max - x_min).max(1e-6);
     let y_range = (y_max - y_min).max(1e-6);
 
-    let (x_int, x_count) = x_info;
-    let (y_int, y_count) = y_info;
-
     let font_size = 12.0; // 这个现在正确传给 size 参数
     let [r, g, b, a] = color::get_fg();
     let text_color = Color::from_rgba8(r, g, b, a);
 
+    // a twin puts its ticks on the opposite side
+    let x_label_y = match self.config.twin {
+      Some(Twin::Y) => ui_ts.ty - h - 10.0 - font_size,
+      _ => ui_ts.ty + 10.0,
+    };
+    let y_label_x = |text_w: f32| match self.config.twin {
+      Some(Twin::X) => ui_ts.tx + w + 8.0,
+      _ => ui_ts.tx - text_w - 8.0,
+    };
+
+    let (x_labels, y_labels) = self.visible_tick_labels(x_info, y_info);
+
     // 1. 绘制 X 轴刻度 (标签在轴下方)
-    let x_start_val = (x_min / x_int).floor() * x_int;
-    for i in 0..x_count {
-      let x_val = x_start_val + (i as f32 * x_int);
-      if x_val >= x_min && x_val <= x_max + 1e-6 {
-        let px = (x_val - x_min) / x_range * w;
-        let label = format!("{:.1}", x_val);
-
-        // 修正居中：根据字符数量估算宽度，font_size * 0.5 是平均字符宽度
-        let text_w = label.len() as f32 * (font_size * 0.5);
-
-        tr.draw(
-          pixmap,
-          &label,
-          ui_ts.tx + px - (text_w / 2.0), // X: 轴原点 + 偏移 - 半宽
-          ui_ts.ty + 10.0,                // Y: 轴原点下方 10 像素
-          font_size,                      // Size
-          text_color,
-        );
-      }
+    for (x_val, label) in x_labels {
+      let px = (x_val - x_min) / x_range * w;
+
+      // 修正居中：按字形度量的宽度居中
+      let text_w = tr.measure(&label, font_size).0;
+
+      tr.draw(
+        pixmap,
+        &label,
+        ui_ts.tx + px - (text_w / 2.0), // X: 轴原点 + 偏移 - 半宽
+        x_label_y,                      // Y: 轴原点下方 10 像素
+        font_size,                      // Size
+        text_color,
+      );
     }
 
     // 2. 绘制 Y 轴刻度 (标签在轴左侧)
-    let y_start_val = (y_min / y_int).floor() * y_int;
-    for i in 0..y_count {
-      let y_val = y_start_val + (i as f32 * y_int);
-      if y_val >= y_min && y_val <= y_max + 1e-6 {
-        let py = -((y_val - y_min) / y_range * h); // 笛卡尔转屏幕坐标
-        let label = format!("{:.1}", y_val);
-
-        let text_w = label.len() as f32 * (font_size * 0.5);
-
-        tr.draw(
-          pixmap,
-          &label,
-          ui_ts.tx - text_w - 8.0,           // X: 轴原点左边，留 8px 间距
-          ui_ts.ty + py - (font_size / 2.0), // Y: 居中对齐刻度线
-          font_size,                         // Size
-          text_color,
-        );
+    for (y_val, label) in y_labels {
+      let py = -((y_val - y_min) / y_range * h); // 笛卡尔转屏幕坐标
+
+      let text_w = tr.measure(&label, font_size).0;
+
+      tr.draw(
+        pixmap,
+        &label,
+        y_label_x(text_w),                 // X: 轴原点左边，留 8px 间距
+        ui_ts.ty + py - (font_size / 2.0), // Y: 居中对齐刻度线
+        font_size,                         // Size
+        text_color,
+      );
+    }
+  }
+  /// the shown `(x, y)` tick labels inside the limits, empty when hidden
+  fn visible_tick_labels(
+    &self, x_info: (f32, usize), y_info: (f32, usize),
+  ) -> (TickLabels, TickLabels) {
+    let (x_min, x_max) = self.config.x_limit.unwrap_or((0.0, 1.0));
+    let (y_min, y_max) = self.config.y_limit.unwrap_or((0.0, 1.0));
+    let x_labels = if self.config.x_tick_labels {
+      self
+        .category_labels(|d| d.x_labels())
+        .unwrap_or_else(|| Self::tick_labels(x_min, x_info.0, x_info.1))
+    } else {
+      Vec::new()
+    };
+    let y_labels = if self.config.y_tick_labels {
+      self
+        .category_labels(|d| d.y_labels())
+        .unwrap_or_else(|| Self::tick_labels(y_min, y_info.0, y_info.1))
+    } else {
+      Vec::new()
+    };
+    (
+      x_labels
+        .into_iter()
+        .filter(|(v, _)| *v >= x_min && *v <= x_max + 1e-6)
+        .collect(),
+      y_labels
+        .into_iter()
+        .filter(|(v, _)| *v >= y_min && *v <= y_max + 1e-6)
+        .collect(),
+    )
+  }
+  /// tick intervals of the current limits, as used by `render_axis`
+  fn tick_info(&self) -> ((f32, usize), (f32, usize)) {
+    let (x_min, x_max) = self.config.x_limit.unwrap_or((-1.0, 1.0));
+    let (y_min, y_max) = self.config.y_limit.unwrap_or((-1.0, 1.0));
+    (
+      Self::calculate_tick_interval((x_max - x_min).max(1e-6)),
+      Self::calculate_tick_interval((y_max - y_min).max(1e-6)),
+    )
+  }
+  /// pixels the text around the plot area takes on each side, measured with the font
+  fn text_extents(&self, tr: &TextRender) -> Margins {
+    let font_size = 12.0;
+    let text_h = tr.measure("0", font_size).1;
+    let width = |s: &str| tr.measure(s, font_size).0;
+    let widest =
+      |labels: &[(f32, String)]| labels.iter().map(|(_, l)| width(l)).fold(0.0, f32::max);
+    let (x_info, y_info) = self.tick_info();
+    let (x_labels, y_labels) = self.visible_tick_labels(x_info, y_info);
+    let mut m = Margins::uniform(0.0);
+
+    // tick labels below the plot overhang the sides by half their width
+    if let (Some((_, first)), Some((_, last))) = (x_labels.first(), x_labels.last()) {
+      m.bottom = 10.0 + text_h;
+      m.left = width(first) / 2.0;
+      m.right = width(last) / 2.0;
+    }
+    if !y_labels.is_empty() {
+      m.left = m.left.max(widest(&y_labels) + 8.0);
+      m.top = text_h / 2.0;
+    }
+    if let Some(label) = &self.config.x_label {
+      m.bottom = 28.0 + text_h;
+      m.left = m.left.max(width(label) / 2.0);
+    }
+    if self.config.y_label.is_some() {
+      m.left = m.left.max(Self::y_label_gap(&y_labels, tr) + text_h);
+    }
+    for twin in &self.twins {
+      let (x_info, y_info) = twin.tick_info();
+      let (x_labels, y_labels) = twin.visible_tick_labels(x_info, y_info);
+      match twin.config.twin {
+        Some(Twin::X) => {
+          m.right = m.right.max(widest(&y_labels) + 8.0);
+          if twin.config.y_label.is_some() {
+            m.right = m.right.max(Self::y_label_gap(&y_labels, tr) + text_h);
+          }
+        }
+        Some(Twin::Y) => {
+          if !x_labels.is_empty() {
+            m.top = m.top.max(10.0 + text_h);
+          }
+          if twin.config.x_label.is_some() {
+            m.top = m.top.max(28.0 + text_h);
+          }
+        }
+        None => {}
       }
     }
+    if let Some(title) = &self.config.title {
+      m.top += Self::TITLE_GAP + tr.measure(title, Self::TITLE_SIZE).1;
+    }
+    m
+  }
+  /// title centered above the plot area and the labels over it
+  fn draw_title(&self, pixmap: &mut Pixmap, ui_ts: &Transform, tr: &TextRender, size: (f32, f32)) {
+    let Some(title) = &self.config.title else {
+      return;
+    };
+    let (w, h) = size;
+    let text_h = tr.measure(title, Self::TITLE_SIZE).1;
+    let mut above = self.text_extents(tr).top - Self::TITLE_GAP - text_h;
+    // the fixed margin may be too small for the text above the plot area, the title then
+    // overlaps it rather than leaving the viewport
+    let room = ui_ts.ty - h - self.y - Self::TITLE_GAP - text_h;
+    above = above.min(room).max(0.0);
+    let style = TextStyle {
+      size: Self::TITLE_SIZE,
+      h_align: HAlign::Center,
+      v_align: VAlign::Bottom,
+      ..TextStyle::default()
+    };
+    tr.draw_styled(
+      pixmap,
+      title,
+      ui_ts.tx + w / 2.0,
+      ui_ts.ty - h - above - Self::TITLE_GAP,
+      &style,
+      None,
+    );
+  }
+  /// numeric tick labels starting from the first multiple of `interval` below `min`
+  fn tick_labels(min: f32, interval: f32, count: usize) -> Vec<(f32, String)> {
+    let start = (min / interval).floor() * interval;
+    (0..count)
+      .map(|i| {
+        let val = start + i as f32 * interval;
+        (val, format!("{:.1}", val))
+      })
+      .collect()
+  }
+  /// categorical labels of the first drawable providing them
+  fn category_labels(
+    &self, f: impl Fn(&dyn Drawable) -> Option<Vec<(f32, String)>>,
+  ) -> Option<Vec<(f32, String)>> {
+    self.drawables.iter().find_map(|d| f(d.as_ref()))
   }
   /// 绘制 X 轴
   fn draw_axis_x(&self, pixmap: &mut Pixmap, ts: &Transform, w: f32, origin_y: f32) {
@@ -202,7 +457,7 @@ impl Axis {
     pb.move_to(w, origin_y);
     pb.line_to(w - arrow_len, origin_y + arrow_len * 0.5);
 
-    self.stroke_path(pixmap, pb, ts, 1.5, color::get_fg());
+    self.stroke_path(pixmap, pb, ts, 1.5, color::get_fg(), None);
   }
 
   /// 绘制 Y 轴
@@ -219,12 +474,13 @@ impl Axis {
     pb.move_to(origin_x, -h);
     pb.line_to(origin_x + arrow_len * 0.5, -h + arrow_len);
 
-    self.stroke_path(pixmap, pb, ts, 1.5, color::get_fg());
+    self.stroke_path(pixmap, pb, ts, 1.5, color::get_fg(), None);
   }
 
   /// 公用渲染辅助
   fn stroke_path(
     &self, pixmap: &mut Pixmap, pb: PathBuilder, ts: &Transform, width: f32, color: [u8; 4],
+    dash: Option<StrokeDash>,
   ) {
     if let Some(path) = pb.finish() {
       let mut paint = Paint::default();
@@ -235,6 +491,7 @@ impl Axis {
         &paint,
         &Stroke {
           width,
+          dash,
           ..Default::default()
         },
         *ts,
@@ -245,7 +502,7 @@ impl Axis {
   /// calculate a "nice" tick interval and number of ticks for a given range
   /// ## parameter
   /// * range: f32 - the data range (max - min)
-  fn calculate_tick_interval(range: f32) -> (f32, usize) {
+  pub(crate) fn calculate_tick_interval(range: f32) -> (f32, usize) {
     if range <= 0.0 {
       return (1.0, 1);
     }
@@ -271,12 +528,9 @@ impl Axis {
     let num_ticks = (range / nice_interval).ceil() as usize + 1;
     (nice_interval, num_ticks)
   }
-  fn auto_limit(&mut self) {
-    if self.config.x_limit.is_some() && self.config.y_limit.is_some() {
-      return;
-    }
-    println!("into autolimit");
-    let mut total_bounds = None;
+  /// union of the bounds of all drawables
+  pub(crate) fn data_bound(&self) -> Option<Bound> {
+    let mut total_bounds: Option<Bound> = None;
 
     for drawable in &self.drawables {
       if let Some(b) = drawable.bound() {
@@ -291,46 +545,72 @@ impl Axis {
         }
       }
     }
-
-    if let Some(b) = total_bounds {
-      let padding = 0.1; // 只给最大值留 10% 的呼吸空间
-
-      let dx = if b.x_max <= b.x_min {
-        1.0
-      } else {
-        (b.x_max - b.x_min) * padding
-      };
-      let dy = if b.y_max <= b.y_min {
-        1.0
-      } else {
-        (b.y_max - b.y_min) * padding
-      };
-
+    // a side without extent, as the x of a lone horizontal line, falls back to 0..1
+    if let Some(tb) = &mut total_bounds {
+      if tb.x_min > tb.x_max {
+        (tb.x_min, tb.x_max) = (0.0, 1.0);
+      }
+      if tb.y_min > tb.y_max {
+        (tb.y_min, tb.y_max) = (0.0, 1.0);
+      }
+    }
+    total_bounds
+  }
+  /// limit fitting `min..max` with some room above the maximum
+  pub(crate) fn padded_limit(min: f32, max: f32) -> (f32, f32) {
+    let padding = 0.1; // 只给最大值留 10% 的呼吸空间
+    let d = if max <= min {
+      1.0
+    } else {
+      (max - min) * padding
+    };
+    (min, max + d)
+  }
+  fn auto_limit(&mut self) {
+    if self.config.x_limit.is_some() && self.config.y_limit.is_some() {
+      return;
+    }
+    if let Some(b) = self.data_bound() {
       if self.config.x_limit.is_none() {
-        self.set_x_limit(Some((b.x_min, b.x_max + dx)));
+        self.set_x_limit(Some(Self::padded_limit(b.x_min, b.x_max)));
       }
       if self.config.y_limit.is_none() {
-        self.set_y_limit(Some((b.y_min, b.y_max + dy)));
+        self.set_y_limit(Some(Self::padded_limit(b.y_min, b.y_max)));
       }
     }
   }
-  pub(crate) fn render(&mut self, pixmap: &mut Pixmap, tr: &TextRender) {
-    self.auto_limit();
-
-    let width = self.viewport.width();
-    let height = self.viewport.height();
-    let margin: f32 = (width * 0.1).min(50.);
+  /// `(ui, data)` transforms of the plot area: the ui one has its origin at the bottom left
+  /// corner of the plot area, the data one maps the data into pixels
+  fn transforms(&self) -> Option<(Transform, Transform, (f32, f32))> {
     let (x_min, x_max) = self.config.x_limit.unwrap_or((0.0, 1.0));
     let (y_min, y_max) = self.config.y_limit.unwrap_or((0.0, 1.0));
+    // a twin fills the plot area of its parent
+    if let Some((ui_ts, (w, h))) = self.frame {
+      if x_max == x_min || y_max == y_min {
+        return None;
+      }
+      let data_ts = ui_ts
+        .pre_scale(w / (x_max - x_min), -h / (y_max - y_min))
+        .pre_translate(-x_min, -y_min);
+      return Some((ui_ts, data_ts, (w, h)));
+    }
+    let width = self.viewport.width();
+    let height = self.viewport.height();
+    let m = self
+      .margins
+      .unwrap_or_else(|| Margins::uniform((width * 0.1).min(50.)));
     let x_range = x_max - x_min;
     let y_range = y_max - y_min;
 
     if x_range == 0.0 || y_range == 0.0 {
-      return;
+      return None;
     }
 
-    let plot_w = width - 2. * margin;
-    let plot_h = height - 2. * margin;
+    let plot_w = width - m.left - m.right;
+    let plot_h = height - m.top - m.bottom;
+    if plot_w <= 0.0 || plot_h <= 0.0 {
+      return None;
+    }
 
     // --- 根据策略计算缩放 ---
     let (scale_x, scale_y, offset_x, offset_y) = match self.config.strategy {
@@ -350,24 +630,186 @@ impl Axis {
     let base_ts = Transform::from_translate(self.x, self.y);
 
     // UI 变换（用于坐标轴）：仅平移到绘图区域左下角
-    let ui_ts = base_ts.pre_translate(margin + offset_x, height - (margin + offset_y));
+    let ui_ts = base_ts.pre_translate(m.left + offset_x, height - (m.bottom + offset_y));
 
     // 数据变换（用于数据绘制）：平移+缩放+翻转
     let data_ts = ui_ts
       .pre_scale(scale_x, -scale_y)
       .pre_translate(-x_min, -y_min);
+    Some((ui_ts, data_ts, (x_range * scale_x, y_range * scale_y)))
+  }
+  /// limits of the axis and its twins as drawn, the automatic ones filled from the data
+  fn update_limits(&mut self) {
+    self.share_twin_limits();
+    self.auto_limit();
+    for twin in &mut self.twins {
+      match twin.config.twin {
+        Some(Twin::X) => twin.config.x_limit = self.config.x_limit,
+        Some(Twin::Y) => twin.config.y_limit = self.config.y_limit,
+        None => {}
+      }
+      twin.auto_limit();
+    }
+  }
+  /// margins fitting the text of a cartesian axis with the tight layout, `None` otherwise
+  pub(crate) fn tight_margins(&mut self, tr: &TextRender) -> Option<Margins> {
+    if !self.config.tight_layout || self.config.projection != Projection::Cartesian {
+      return None;
+    }
+    self.update_limits();
+    let mut m = self.text_extents(tr);
+    for side in [&mut m.left, &mut m.right, &mut m.top, &mut m.bottom] {
+      *side += Self::TIGHT_PAD;
+    }
+    Some(m)
+  }
+  /// replaces the tight margins by `margins`, `None` goes back to the measured ones
+  pub(crate) fn set_grid_margins(&mut self, margins: Option<Margins>) {
+    self.grid_margins = margins;
+  }
+  pub(crate) fn render(&mut self, pixmap: &mut Pixmap, tr: &TextRender) {
+    match self.config.projection {
+      Projection::Polar => return self.render_polar(pixmap, tr),
+      Projection::ThreeD => return self.render_3d(pixmap, tr),
+      Projection::Cartesian => {}
+    }
+    self.update_limits();
+    if self.config.tight_layout {
+      self.margins = self.grid_margins.or_else(|| self.tight_margins(tr));
+    }
+    let Some((ui_ts, data_ts, size)) = self.transforms() else {
+      return;
+    };
 
     // 绘制坐标轴：使用 UI 变换
-    self.render_axis(pixmap, &ui_ts, tr);
+    self.render_axis(pixmap, &ui_ts, size, tr);
 
-    // 绘制数据：使用数据变换
+    // 绘制数据：使用数据变换，裁剪到绘图区域
+    self.assign_colors();
+    let mask = Self::plot_mask(pixmap, &ui_ts, size);
+    for drawable in &self.drawables {
+      let mask = mask.as_ref().filter(|_| drawable.clip());
+      drawable.draw(pixmap, &data_ts, mask);
+    }
+    let axes_ts = ui_ts.pre_scale(size.0, -size.1);
+    for drawable in &self.drawables {
+      let mask = mask.as_ref().filter(|_| drawable.clip());
+      drawable.draw_text(pixmap, &data_ts, &axes_ts, mask, tr);
+    }
+
+    for twin in &mut self.twins {
+      twin.frame = Some((ui_ts, size));
+      // continue the palette of the parent
+      if twin.color_index == 0 {
+        twin.color_index = self.color_index;
+      }
+      twin.render(pixmap, tr);
+    }
+  }
+  /// mask of the plot area, `ui_ts` maps its bottom left corner to the origin
+  fn plot_mask(pixmap: &Pixmap, ui_ts: &Transform, size: (f32, f32)) -> Option<Mask> {
+    let rect = Rect::from_xywh(ui_ts.tx, ui_ts.ty - size.1, size.0, size.1)?;
+    Self::path_mask(pixmap, &PathBuilder::from_rect(rect))
+  }
+  /// mask of the pixels inside `path`
+  fn path_mask(pixmap: &Pixmap, path: &Path) -> Option<Mask> {
+    let mut mask = Mask::new(pixmap.width(), pixmap.height())?;
+    mask.fill_path(path, FillRule::Winding, true, Transform::identity());
+    Some(mask)
+  }
+  /// fit the limit shared with the twins to the data of all of them
+  fn share_twin_limits(&mut self) {
+    let union = |axes: &mut dyn Iterator<Item = &Axis>, x: bool| {
+      axes
+        .filter_map(|a| a.data_bound())
+        .map(|b| {
+          if x {
+            (b.x_min, b.x_max)
+          } else {
+            (b.y_min, b.y_max)
+          }
+        })
+        .reduce(|(lo, hi), (l, h)| (lo.min(l), hi.max(h)))
+        .map(|(lo, hi)| Self::padded_limit(lo, hi))
+    };
+    let has = |twin: Twin| self.twins.iter().any(|t| t.config.twin == Some(twin));
+    if self.config.x_limit.is_none() && has(Twin::X) {
+      let twins = self.twins.iter().filter(|t| t.config.twin == Some(Twin::X));
+      self.config.x_limit = union(&mut std::iter::once(&*self).chain(twins), true);
+    }
+    if self.config.y_limit.is_none() && has(Twin::Y) {
+      let twins = self.twins.iter().filter(|t| t.config.twin == Some(Twin::Y));
+      self.config.y_limit = union(&mut std::iter::once(&*self).chain(twins), false);
+    }
+  }
+  /// Creates a secondary axis sharing the plot area and the x limit of this one.
+  ///
+  /// It has its own y limit, its ticks and label are drawn on the right side. Only cartesian
+  /// axes draw their twins, those of a polar or 3D axis are ignored.
+  pub fn twinx(&mut self) -> &mut Axis {
+    self.add_twin(Twin::X)
+  }
+  /// Creates a secondary axis sharing the plot area and the y limit of this one.
+  ///
+  /// It has its own x limit, its ticks and label are drawn on the top side. Only cartesian
+  /// axes draw their twins, those of a polar or 3D axis are ignored.
+  pub fn twiny(&mut self) -> &mut Axis {
+    self.add_twin(Twin::Y)
+  }
+  fn add_twin(&mut self, twin: Twin) -> &mut Axis {
+    let mut axis = Axis::new(
+      self.x,
+      self.y,
+      (self.viewport.width(), self.viewport.height()),
+    );
+    axis.config.twin = Some(twin);
+    // the grid of the parent is enough
+    axis.config.major_grid = None;
+    match twin {
+      Twin::X => axis.config.x_tick_labels = false,
+      Twin::Y => axis.config.y_tick_labels = false,
+    }
+    self.twins.push(axis);
+    self.twins.last_mut().unwrap()
+  }
+  /// move the limits of a cartesian axis by a drag of `(dx, dy)` pixels
+  pub(crate) fn pan(&mut self, dx: f32, dy: f32) {
+    for twin in &mut self.twins {
+      twin.pan(dx, dy);
+    }
+    let Some((_, data_ts, _)) = self.transforms() else {
+      return;
+    };
+    let (dx, dy) = (-dx / data_ts.sx, -dy / data_ts.sy);
+    if let Some((lo, hi)) = self.config.x_limit {
+      self.config.x_limit = Some((lo + dx, hi + dx));
+    }
+    if let Some((lo, hi)) = self.config.y_limit {
+      self.config.y_limit = Some((lo + dy, hi + dy));
+    }
+  }
+  /// scale the limits of a cartesian axis by `factor` around the pixel `at`, below 1 zooms in
+  pub(crate) fn zoom(&mut self, factor: f32, at: (f32, f32)) {
+    for twin in &mut self.twins {
+      twin.zoom(factor, at);
+    }
+    let Some(inverse) = self.transforms().and_then(|(_, ts, _)| ts.invert()) else {
+      return;
+    };
+    let mut p = Point::from_xy(at.0, at.1);
+    inverse.map_point(&mut p);
+    let scale = |(lo, hi): (f32, f32), c: f32| (c + (lo - c) * factor, c + (hi - c) * factor);
+    self.config.x_limit = self.config.x_limit.map(|l| scale(l, p.x));
+    self.config.y_limit = self.config.y_limit.map(|l| scale(l, p.y));
+  }
+  /// give every drawable without a color the next one of the palette
+  fn assign_colors(&mut self) {
     for drawable in &mut self.drawables {
       if drawable.get_color() == [0, 0, 0, 0] {
         let color = color::get_color(self.color_index & 7);
         self.color_index += 1;
         drawable.set_color(color);
       }
-      drawable.draw(pixmap, &data_ts);
     }
   }
   pub fn add(&mut self, drawable: Box<dyn Drawable>) {
@@ -379,16 +821,560 @@ impl Axis {
   pub fn set_y_limit(&mut self, limit: Option<(f32, f32)>) {
     self.config.y_limit = limit;
   }
+  pub(crate) fn x_limit(&self) -> Option<(f32, f32)> {
+    self.config.x_limit
+  }
+  pub(crate) fn y_limit(&self) -> Option<(f32, f32)> {
+    self.config.y_limit
+  }
+  /// Sets the label of the x axis, drawn under the plot area.
+  pub fn set_x_label(&mut self, label: &str) {
+    self.config.x_label = Some(label.to_string());
+  }
+  /// Sets the label of the y axis, drawn upward beside the tick labels on the left side.
+  pub fn set_y_label(&mut self, label: &str) {
+    self.config.y_label = Some(label.to_string());
+  }
+  /// Sets the title drawn above the plot area.
+  pub fn set_title(&mut self, title: &str) {
+    self.config.title = Some(title.to_string());
+  }
+  /// Fits the margins around the plot area to the measured tick labels, axis labels and title,
+  /// instead of the fixed margin of a tenth of the width (at most 50 pixels).
+  pub fn set_tight_layout(&mut self, tight: bool) {
+    self.config.tight_layout = tight;
+    if !tight {
+      self.margins = None;
+    }
+  }
+  /// Shows or hides the tick labels of the x axis, shown by default.
+  pub fn set_x_tick_labels(&mut self, show: bool) {
+    self.config.x_tick_labels = show;
+  }
+  /// Shows or hides the tick labels of the y axis, shown by default.
+  pub fn set_y_tick_labels(&mut self, show: bool) {
+    self.config.y_tick_labels = show;
+  }
+  /// whether the `(x, y)` tick labels are shown
+  #[cfg(test)]
+  pub(crate) fn shows_tick_labels(&self) -> (bool, bool) {
+    (self.config.x_tick_labels, self.config.y_tick_labels)
+  }
+  /// Sets the z limit of a 3d axis, `None` fits the data.
+  pub fn set_z_limit(&mut self, limit: Option<(f32, f32)>) {
+    self.config.z_limit = limit;
+  }
   pub fn set_strategy(&mut self, strategy: ScaleStrategy) {
     self.config.strategy = strategy;
   }
+  /// Sets the line style of the grid lines at the major ticks.
+  ///
+  /// `None` hides the major grid, default is `Some(LineStyle::Solid)`.
+  pub fn set_major_grid(&mut self, style: Option<LineStyle>) {
+    self.config.major_grid = style;
+  }
+  /// Sets the line style of the grid lines between the major ticks.
+  ///
+  /// `None` hides the minor grid, which is the default.
+  pub fn set_minor_grid(&mut self, style: Option<LineStyle>) {
+    self.config.minor_grid = style;
+  }
+  /// Sets how the data is projected, default is `Projection::Cartesian`.
+  ///
+  /// In `Projection::Polar` the x data is the angle in radians and the y data the radius,
+  /// `set_y_limit` sets the radial range, data beyond it is clipped to the circle, and the x limit
+  /// is not used.
+  ///
+  /// In `Projection::ThreeD` only 3d drawables (`Line3D`, `Scatter3D`, `Surface`) are drawn.
+  ///
+  /// The twins of `twinx` and `twiny` are only drawn in `Projection::Cartesian`.
+  pub fn set_projection(&mut self, projection: Projection) {
+    self.config.projection = projection;
+  }
+  /// Sets where the angle `0` points in a polar axis, default is `ThetaZero::East`.
+  pub fn set_theta_zero(&mut self, zero: ThetaZero) {
+    self.config.theta_zero = zero;
+  }
+  /// Sets the direction the angle grows in a polar axis, default is counter-clockwise.
+  pub fn set_theta_direction(&mut self, direction: ThetaDirection) {
+    self.config.theta_direction = direction;
+  }
+  /// Sets the unit of the angle labels of a polar axis, default is `AngleUnit::Degrees`.
+  pub fn set_angle_unit(&mut self, unit: AngleUnit) {
+    self.config.angle_unit = unit;
+  }
+  /// Sets the camera of a 3d axis in degrees, default is `(30.0, -60.0)`.
+  ///
+  /// # Arguments
+  ///
+  /// * `elevation` - The angle above the x-y plane, clamped to `-90..=90`.
+  /// * `azimuth` - The rotation around the z axis, `0` looks along the x axis toward the origin.
+  pub fn set_view(&mut self, elevation: f32, azimuth: f32) {
+    self.config.elevation = elevation.clamp(-90.0, 90.0);
+    self.config.azimuth = azimuth;
+  }
+  /// turn the camera of a 3d axis, used by the mouse drag in the window
+  pub(crate) fn rotate(&mut self, d_elevation: f32, d_azimuth: f32) {
+    let azimuth = (self.config.azimuth + d_azimuth).rem_euclid(360.0);
+    self.set_view(self.config.elevation + d_elevation, azimuth);
+  }
+  pub(crate) fn projection(&self) -> Projection {
+    self.config.projection
+  }
+  /// the rectangle of the axis in the figure, margins included
+  pub(crate) fn bounds(&self) -> Option<Rect> {
+    Rect::from_xywh(
+      self.x,
+      self.y,
+      self.viewport.width(),
+      self.viewport.height(),
+    )
+  }
+  /// pixels of the plot area of a cartesian axis
+  pub(crate) fn plot_rect(&self) -> Option<Rect> {
+    let (ui_ts, _, (w, h)) = self.transforms()?;
+    Rect::from_xywh(ui_ts.tx, ui_ts.ty - h, w, h)
+  }
+  /// pixels of the data rectangle `x_limit` by `y_limit` in a cartesian axis
+  pub(crate) fn data_rect(&self, x_limit: (f32, f32), y_limit: (f32, f32)) -> Option<Rect> {
+    let (_, data_ts, _) = self.transforms()?;
+    let mut p1 = Point::from_xy(x_limit.0, y_limit.1);
+    let mut p2 = Point::from_xy(x_limit.1, y_limit.0);
+    data_ts.map_point(&mut p1);
+    data_ts.map_point(&mut p2);
+    Rect::from_ltrb(
+      p1.x.min(p2.x),
+      p1.y.min(p2.y),
+      p1.x.max(p2.x),
+      p1.y.max(p2.y),
+    )
+  }
+  /// whether the pixel `(x, y)` of the figure is inside the axis
+  pub(crate) fn contains(&self, x: f32, y: f32) -> bool {
+    x >= self.x
+      && y >= self.y
+      && x < self.x + self.viewport.width()
+      && y < self.y + self.viewport.height()
+  }
+}
+
+// implement for the polar projection
+impl Axis {
+  /// angle between two spokes of the angular grid, in degrees
+  const SPOKE_STEP: i32 = 30;
+
+  /// radial range from the y limit, or from 0 to a "nice" value above the largest radius
+  pub(crate) fn polar_limit(&self) -> (f32, f32) {
+    if let Some(limit) = self.config.y_limit {
+      return limit;
+    }
+    let r_max = self
+      .drawables
+      .iter()
+      .filter_map(|d| d.bound_polar())
+      .map(|b| b.y_max)
+      .fold(0.0, f32::max);
+    if r_max <= 0.0 {
+      return (0.0, 1.0);
+    }
+    let (interval, _) = Self::calculate_tick_interval(r_max);
+    (0.0, (r_max / interval).ceil() * interval)
+  }
+  fn render_polar(&mut self, pixmap: &mut Pixmap, tr: &TextRender) {
+    let width = self.viewport.width();
+    let height = self.viewport.height();
+    let margin = (width * 0.1).min(50.);
+    let radius = ((width - 2.0 * margin).min(height - 2.0 * margin) / 2.0).max(1.0);
+    let center = Point::from_xy(self.x + width / 2.0, self.y + height / 2.0);
+    let (r_min, r_max) = self.polar_limit();
+    let polar = Polar::new(
+      center,
+      radius,
+      (r_min, r_max),
+      self.config.theta_zero,
+      self.config.theta_direction,
+    );
+    // about half as many rings as cartesian ticks, the radial labels share one spoke gap
+    let (r_int, _) = Self::calculate_tick_interval((r_max - r_min) * 2.0);
+    // drawables with categories (e.g. `Radar`) replace the spokes every 30 degrees and the rings
+    let symbols = tr.has_glyph('°') && tr.has_glyph('π');
+    let spokes = self.category_labels(|d| d.x_labels()).unwrap_or_else(|| {
+      (0..360)
+        .step_by(Self::SPOKE_STEP as usize)
+        .map(|deg| {
+          let label = polar::angle_label(deg, self.config.angle_unit, symbols);
+          ((deg as f32).to_radians(), label)
+        })
+        .collect()
+    });
+    let rings = self.category_labels(|d| d.y_labels()).unwrap_or_else(|| {
+      let mut rings = Vec::new();
+      let mut r = r_min + r_int;
+      while r <= r_max + r_int * 0.01 {
+        rings.push((r, format!("{:.1}", r)));
+        r += r_int;
+      }
+      rings
+    });
+
+    // grid: a ring at each radial tick, a spoke at each angle
+    if let Some(style) = &self.config.major_grid {
+      let mut pb = PathBuilder::new();
+      for &(r, _) in rings
+        .iter()
+        .filter(|(r, _)| *r > r_min && *r < r_max - r_int * 0.01)
+      {
+        polar.push_polyline(&mut pb, &[(0.0, r), (std::f32::consts::TAU, r)]);
+      }
+      for &(theta, _) in &spokes {
+        let (p1, p2) = (polar.map(theta, r_min), polar.map(theta, r_max));
+        pb.move_to(p1.x, p1.y);
+        pb.line_to(p2.x, p2.y);
+      }
+      self.stroke_path(
+        pixmap,
+        pb,
+        &Transform::identity(),
+        1.,
+        color::get_gray(),
+        style.dash(1.),
+      );
+    }
+    if let Some(circle) = PathBuilder::from_circle(center.x, center.y, radius) {
+      let mut pb = PathBuilder::new();
+      pb.push_path(&circle);
+      self.stroke_path(
+        pixmap,
+        pb,
+        &Transform::identity(),
+        1.5,
+        color::get_fg(),
+        None,
+      );
+    }
+
+    self.assign_colors();
+    let mask = PathBuilder::from_circle(center.x, center.y, radius)
+      .and_then(|circle| Self::path_mask(pixmap, &circle));
+    for drawable in &self.drawables {
+      let mask = mask.as_ref().filter(|_| drawable.clip());
+      drawable.draw_polar(pixmap, &polar, mask);
+    }
+
+    // labels on top of the data: angles outside the outer ring, radii along the first spoke gap
+    let font_size = 12.0;
+    let [r, g, b, a] = color::get_fg();
+    let text_color = Color::from_rgba8(r, g, b, a);
+    for (theta, label) in &spokes {
+      let text_w = tr.measure(label, font_size).0;
+      let p = polar.map(*theta, r_max);
+      let (dx, dy) = (p.x - center.x, p.y - center.y);
+      let d = dx.hypot(dy).max(1e-6);
+      let (x, y) = (p.x + dx / d * 14.0, p.y + dy / d * 14.0);
+      // long labels grow away from the circle on the left and right
+      let x = x - text_w / 2.0 * (1.0 - dx / d);
+      tr.draw(pixmap, label, x, y - font_size / 2.0, font_size, text_color);
+    }
+    // halfway into the first spoke gap
+    let label_theta = match spokes.as_slice() {
+      [(t0, _), (t1, _), ..] => (t0 + t1) / 2.0,
+      _ => (Self::SPOKE_STEP as f32 / 2.0).to_radians(),
+    };
+    for (r, label) in rings
+      .iter()
+      .filter(|(r, _)| *r > r_min && *r <= r_max + r_int * 0.01)
+    {
+      let p = polar.map(label_theta, *r);
+      tr.draw(
+        pixmap,
+        label,
+        p.x + 2.0,
+        p.y - font_size / 2.0,
+        font_size,
+        text_color,
+      );
+    }
+  }
+}
+
+// implement for the 3d projection
+impl Axis {
+  /// limits of the three axes, from the set limits or "nice" values around the data
+  fn limits_3d(&self) -> [(f32, f32); 3] {
+    let bounds: Vec<_> = self.drawables.iter().filter_map(|d| d.bound_3d()).collect();
+    let fit = |limit: Option<(f32, f32)>, lo: f32, hi: f32| {
+      if let Some(limit) = limit {
+        return limit;
+      }
+      if !lo.is_finite() || !hi.is_finite() {
+        return (0.0, 1.0);
+      }
+      if hi - lo <= 0.0 {
+        return (lo - 1.0, hi + 1.0);
+      }
+      let (interval, _) = Self::calculate_tick_interval(hi - lo);
+      (
+        (lo / interval).floor() * interval,
+        (hi / interval).ceil() * interval,
+      )
+    };
+    let min = |f: fn(&Bound3D) -> f32| bounds.iter().map(f).fold(f32::INFINITY, f32::min);
+    let max = |f: fn(&Bound3D) -> f32| bounds.iter().map(f).fold(f32::NEG_INFINITY, f32::max);
+    [
+      fit(self.config.x_limit, min(|b| b.x_min), max(|b| b.x_max)),
+      fit(self.config.y_limit, min(|b| b.y_min), max(|b| b.y_max)),
+      fit(self.config.z_limit, min(|b| b.z_min), max(|b| b.z_max)),
+    ]
+  }
+  /// about half as many ticks as a cartesian axis, inside `lo..=hi`
+  fn ticks_3d(lo: f32, hi: f32) -> Vec<f32> {
+    let (interval, _) = Self::calculate_tick_interval((hi - lo) * 2.0);
+    let mut ticks = Vec::new();
+    let mut v = (lo / interval).ceil() * interval;
+    while v <= hi + interval * 0.01 {
+      ticks.push(v);
+      v += interval;
+    }
+    ticks
+  }
+  fn render_3d(&mut self, pixmap: &mut Pixmap, tr: &TextRender) {
+    let width = self.viewport.width();
+    let height = self.viewport.height();
+    let margin = (width * 0.1).min(50.);
+    // a cube seen from any angle fits in its circumscribed sphere
+    let scale = ((width - 2.0 * margin).min(height - 2.0 * margin) / 2.0 / 3_f32.sqrt()).max(1.0);
+    let center = Point::from_xy(self.x + width / 2.0, self.y + height / 2.0);
+    let limits = self.limits_3d();
+    let view = View3D::new(
+      center,
+      scale,
+      limits,
+      self.config.elevation,
+      self.config.azimuth,
+    );
+
+    // the panes facing the viewer are the far sides of the box
+    let toward = view.toward();
+    let far: Vec<f32> = (0..3)
+      .map(|k| {
+        if toward[k] > 0.0 {
+          limits[k].0
+        } else {
+          limits[k].1
+        }
+      })
+      .collect();
+    let near: Vec<f32> = (0..3)
+      .map(|k| {
+        if toward[k] > 0.0 {
+          limits[k].1
+        } else {
+          limits[k].0
+        }
+      })
+      .collect();
+    let ticks: Vec<Vec<f32>> = limits
+      .iter()
+      .map(|&(lo, hi)| Self::ticks_3d(lo, hi))
+      .collect();
+    let map = |p: [f32; 3]| view.map(p[0], p[1], p[2]);
+
+    // grid on each far pane: lines of the two other axes
+    if let Some(style) = &self.config.major_grid {
+      let mut pb = PathBuilder::new();
+      for k in 0..3 {
+        for (a, b) in [((k + 1) % 3, (k + 2) % 3), ((k + 2) % 3, (k + 1) % 3)] {
+          for &t in &ticks[a] {
+            let mut p1 = [0.0; 3];
+            p1[k] = far[k];
+            p1[a] = t;
+            p1[b] = limits[b].0;
+            let mut p2 = p1;
+            p2[b] = limits[b].1;
+            let (p1, p2) = (map(p1), map(p2));
+            pb.move_to(p1.x, p1.y);
+            pb.line_to(p2.x, p2.y);
+          }
+        }
+      }
+      self.stroke_path(
+        pixmap,
+        pb,
+        &Transform::identity(),
+        1.,
+        color::get_gray(),
+        style.dash(1.),
+      );
+    }
+    // outline of the far panes
+    let mut pb = PathBuilder::new();
+    for k in 0..3 {
+      let (a, b) = ((k + 1) % 3, (k + 2) % 3);
+      let corner = |u: f32, v: f32| {
+        let mut p = [0.0; 3];
+        p[k] = far[k];
+        p[a] = u;
+        p[b] = v;
+        map(p)
+      };
+      let ((a0, a1), (b0, b1)) = (limits[a], limits[b]);
+      let points = [
+        corner(a0, b0),
+        corner(a1, b0),
+        corner(a1, b1),
+        corner(a0, b1),
+      ];
+      pb.move_to(points[0].x, points[0].y);
+      for p in &points[1..] {
+        pb.line_to(p.x, p.y);
+      }
+      pb.close();
+    }
+    self.stroke_path(
+      pixmap,
+      pb,
+      &Transform::identity(),
+      1.,
+      color::get_fg(),
+      None,
+    );
+
+    self.assign_colors();
+    // data beyond the limits leaves the box, it stays inside the axis
+    let mask = self
+      .bounds()
+      .and_then(|rect| Self::path_mask(pixmap, &PathBuilder::from_rect(rect)));
+    for drawable in &self.drawables {
+      let mask = mask.as_ref().filter(|_| drawable.clip());
+      drawable.draw_3d(pixmap, &view, mask);
+    }
+
+    // tick labels on the bottom edges in front (x, y) and the left vertical edge (z)
+    let font_size = 12.0;
+    let [r, g, b, a] = color::get_fg();
+    let text_color = Color::from_rgba8(r, g, b, a);
+    let floor = if toward[2] > 0.0 {
+      limits[2].0
+    } else {
+      limits[2].1
+    };
+    let z_corner = [[near[0], far[1]], [far[0], near[1]]]
+      .into_iter()
+      .min_by(|p, q| {
+        let (p, q) = (map([p[0], p[1], floor]), map([q[0], q[1], floor]));
+        p.x.total_cmp(&q.x)
+      })
+      .unwrap_or([near[0], near[1]]);
+    let edges = [
+      (0, [0.0, near[1], floor], "x"),
+      (1, [near[0], 0.0, floor], "y"),
+      (2, [z_corner[0], z_corner[1], 0.0], "z"),
+    ];
+    for (k, base, name) in edges {
+      let at = |v: f32| {
+        let mut p = base;
+        p[k] = v;
+        map(p)
+      };
+      let (lo, hi) = limits[k];
+      let mid = at((lo + hi) / 2.0);
+      // away from the center of the box, z labels always to the left
+      let (dx, dy) = if k == 2 {
+        (-1.0, 0.0)
+      } else {
+        let (dx, dy) = (mid.x - center.x, mid.y - center.y);
+        let d = dx.hypot(dy).max(1e-6);
+        (dx / d, dy / d)
+      };
+      let place = |p: Point, label: &str, gap: f32| {
+        let text_w = tr.measure(label, font_size).0;
+        let (x, y) = (p.x + dx * gap, p.y + dy * gap);
+        // grow away from the box on the left and right
+        (x - text_w / 2.0 * (1.0 - dx), y - font_size / 2.0)
+      };
+      let mut longest: f32 = 0.0;
+      for &t in &ticks[k] {
+        let label = format!("{:.1}", t);
+        longest = longest.max(tr.measure(&label, font_size).0);
+        let (x, y) = place(at(t), &label, 14.0);
+        tr.draw(pixmap, &label, x, y, font_size, text_color);
+      }
+      let gap = 30.0 + if dx.abs() > 0.5 { longest } else { 0.0 };
+      let (x, y) = place(mid, name, gap);
+      tr.draw(pixmap, name, x, y, font_size, text_color);
+    }
+  }
 }
 
-#[derive(Default)]
 pub struct Config {
   x_limit: Option<(f32, f32)>,
   y_limit: Option<(f32, f32)>,
   strategy: ScaleStrategy,
+  major_grid: Option<LineStyle>,
+  minor_grid: Option<LineStyle>,
+  projection: Projection,
+  theta_zero: ThetaZero,
+  theta_direction: ThetaDirection,
+  angle_unit: AngleUnit,
+  z_limit: Option<(f32, f32)>,
+  elevation: f32,
+  azimuth: f32,
+  x_tick_labels: bool,
+  y_tick_labels: bool,
+  x_label: Option<String>,
+  y_label: Option<String>,
+  twin: Option<Twin>,
+  title: Option<String>,
+  tight_layout: bool,
+}
+impl Default for Config {
+  fn default() -> Self {
+    Self {
+      x_limit: None,
+      y_limit: None,
+      strategy: ScaleStrategy::default(),
+      major_grid: Some(LineStyle::Solid),
+      minor_grid: None,
+      projection: Projection::default(),
+      theta_zero: ThetaZero::default(),
+      theta_direction: ThetaDirection::default(),
+      angle_unit: AngleUnit::default(),
+      z_limit: None,
+      elevation: 30.0,
+      azimuth: -60.0,
+      x_tick_labels: true,
+      y_tick_labels: true,
+      x_label: None,
+      y_label: None,
+      twin: None,
+      title: None,
+      tight_layout: false,
+    }
+  }
+}
+/// Pixels around the plot area of an `Axis`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct Margins {
+  pub(crate) left: f32,
+  pub(crate) right: f32,
+  pub(crate) top: f32,
+  pub(crate) bottom: f32,
+}
+impl Margins {
+  fn uniform(margin: f32) -> Self {
+    Self {
+      left: margin,
+      right: margin,
+      top: margin,
+      bottom: margin,
+    }
+  }
+}
+/// The limit a twin axis shares with its parent
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Twin {
+  X,
+  Y,
 }
 #[derive(Default)]
 pub enum ScaleStrategy {
@@ -396,3 +1382,135 @@ pub enum ScaleStrategy {
   Fit,
   Stretch,
 }
+/// How the data of an `Axis` is mapped onto the plot area
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum Projection {
+  #[default]
+  Cartesian,
+  /// x is the angle in radians, y the radius
+  Polar,
+  /// `(x, y, z)` data seen through an orthographic camera, see `Axis::set_view`
+  ThreeD,
+}
+
+#[test]
+fn test_tight_margins() {
+  use crate::primitive::{Config as PrimitiveConfig, Curve};
+
+  let tr = TextRender::new();
+  let mut axis = Axis::new(0.0, 0.0, (400.0, 300.0));
+  let mut curve = Curve::new("c".to_string(), PrimitiveConfig::default());
+  curve.set_data(&[0.0, 1.0], &[100000.0, 250000.0]);
+  axis.add(Box::new(curve));
+  axis.set_tight_layout(true);
+  let mut pixmap = Pixmap::new(400, 300).unwrap();
+  axis.render(&mut pixmap, &tr);
+
+  let (x_info, y_info) = axis.tick_info();
+  let (_, y_labels) = axis.visible_tick_labels(x_info, y_info);
+  let widest = y_labels
+    .iter()
+    .map(|(_, l)| tr.measure(l, 12.0).0)
+    .fold(0.0, f32::max);
+  let margins = axis.margins.unwrap();
+  // the labels fit left of the plot area, which is wider than the fixed 40 pixels
+  assert!(margins.left >= widest + 8.0);
+  assert!(margins.left > 40.0);
+}
+
+#[test]
+fn test_title_in_viewport() {
+  use crate::primitive::{Config as PrimitiveConfig, Curve};
+
+  let tr = TextRender::new();
+  let mut axis = Axis::new(0.0, 100.0, (400.0, 300.0));
+  let mut curve = Curve::new("c".to_string(), PrimitiveConfig::default());
+  curve.set_data(&[0.0, 1.0], &[0.0, 1.0]);
+  axis.add(Box::new(curve));
+  axis.set_title("title");
+  // the labels of the twin take more than the fixed margin above the plot area
+  let mut curve = Curve::new("c".to_string(), PrimitiveConfig::default());
+  curve.set_data(&[0.0, 1.0], &[0.0, 1.0]);
+  let twin = axis.twiny();
+  twin.add(Box::new(curve));
+  twin.set_x_label("top");
+  let mut pixmap = Pixmap::new(400, 400).unwrap();
+  axis.render(&mut pixmap, &tr);
+
+  // nothing is drawn above the viewport
+  let above = &pixmap.pixels()[..400 * 100];
+  assert!(above.iter().all(|p| p.alpha() == 0));
+}
+
+#[test]
+fn test_twin_limits() {
+  use crate::primitive::{Config as PrimitiveConfig, Curve};
+
+  let tr = TextRender::new();
+  let mut axis = Axis::new(0.0, 0.0, (400.0, 300.0));
+  let mut curve = Curve::new("c".to_string(), PrimitiveConfig::default());
+  curve.set_data(&[0.0, 5.0], &[0.0, 1.0]);
+  axis.add(Box::new(curve));
+  let mut curve = Curve::new("c".to_string(), PrimitiveConfig::default());
+  curve.set_data(&[2.0, 10.0], &[100.0, 200.0]);
+  axis.twinx().add(Box::new(curve));
+  let mut pixmap = Pixmap::new(400, 300).unwrap();
+  axis.render(&mut pixmap, &tr);
+
+  // the shared x limit covers the data of both, each keeps its own y limit
+  let x_limit = Some(Axis::padded_limit(0.0, 10.0));
+  assert_eq!(axis.x_limit(), x_limit);
+  assert_eq!(axis.twins[0].x_limit(), x_limit);
+  assert_eq!(axis.y_limit(), Some(Axis::padded_limit(0.0, 1.0)));
+  assert_eq!(
+    axis.twins[0].y_limit(),
+    Some(Axis::padded_limit(100.0, 200.0))
+  );
+}
+
+#[test]
+fn test_clip_plot_area() {
+  use crate::primitive::{Config as PrimitiveConfig, Curve};
+
+  let tr = TextRender::new();
+  let mut axis = Axis::new(0.0, 0.0, (400.0, 300.0));
+  let mut curve = Curve::new("c".to_string(), PrimitiveConfig::default());
+  curve.set_data(&[0.0, 10.0], &[1.1, 1.1]);
+  axis.add(Box::new(curve));
+  axis.set_x_limit(Some((0.0, 10.0)));
+  axis.set_y_limit(Some((0.0, 1.0)));
+  let mut pixmap = Pixmap::new(400, 300).unwrap();
+  axis.render(&mut pixmap, &tr);
+
+  // the curve is above the limits, in the margin
+  let (_, data_ts, _) = axis.transforms().unwrap();
+  let mut p = Point::from_xy(5.0, 1.1);
+  data_ts.map_point(&mut p);
+  assert!(p.y > 0.0);
+  assert_eq!(pixmap.pixel(p.x as u32, p.y as u32).unwrap().alpha(), 0);
+}
+
+#[test]
+fn test_clip_polar() {
+  use crate::primitive::{Config as PrimitiveConfig, Curve};
+
+  let tr = TextRender::new();
+  let mut axis = Axis::new(0.0, 0.0, (400.0, 300.0));
+  axis.set_projection(Projection::Polar);
+  let theta: Vec<f32> = (0..=360).map(|d| (d as f32).to_radians()).collect();
+  let mut curve = Curve::new("c".to_string(), PrimitiveConfig::default());
+  curve.set_data(&theta, &vec![1.3; theta.len()]);
+  axis.add(Box::new(curve));
+  axis.set_y_limit(Some((0.0, 1.0)));
+  let mut pixmap = Pixmap::new(400, 300).unwrap();
+  axis.render(&mut pixmap, &tr);
+
+  // the ring of the curve is outside the outer circle, between two angle labels
+  let (center, radius) = (Point::from_xy(200.0, 150.0), 110.0);
+  let a = (-15_f32).to_radians();
+  let (x, y) = (
+    center.x + 1.3 * radius * a.cos(),
+    center.y - 1.3 * radius * a.sin(),
+  );
+  assert_eq!(pixmap.pixel(x as u32, y as u32).unwrap().alpha(), 0);
+}
@@ -37,3 +37,67 @@ pub fn get_fg() -> [u8; 4] {
 pub fn get_gray() -> [u8; 4] {
   GRAY
 }
+
+/// Continuous color maps, for values normalised to `[0, 1]`
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum Colormap {
+  #[default]
+  Viridis,
+  Inferno,
+  Coolwarm,
+  Gray,
+}
+
+const VIRIDIS: [[u8; 3]; 10] = [
+  [68, 1, 84],
+  [72, 40, 120],
+  [62, 73, 137],
+  [49, 104, 142],
+  [38, 130, 142],
+  [31, 158, 137],
+  [53, 183, 121],
+  [110, 206, 88],
+  [181, 222, 43],
+  [253, 231, 37],
+];
+const INFERNO: [[u8; 3]; 10] = [
+  [0, 0, 4],
+  [27, 12, 65],
+  [74, 12, 107],
+  [120, 28, 109],
+  [165, 44, 96],
+  [207, 68, 70],
+  [237, 105, 37],
+  [251, 155, 6],
+  [247, 209, 61],
+  [252, 255, 164],
+];
+const COOLWARM: [[u8; 3]; 7] = [
+  [59, 76, 192],
+  [111, 146, 243],
+  [170, 199, 253],
+  [221, 220, 220],
+  [247, 184, 156],
+  [231, 116, 91],
+  [180, 4, 38],
+];
+const GRAY_MAP: [[u8; 3]; 2] = [[0, 0, 0], [255, 255, 255]];
+
+impl Colormap {
+  /// color at `t`, clamped to `[0, 1]`, linearly interpolated between the stops
+  pub fn map(&self, t: f32) -> [u8; 4] {
+    let stops: &[[u8; 3]] = match self {
+      Colormap::Viridis => &VIRIDIS,
+      Colormap::Inferno => &INFERNO,
+      Colormap::Coolwarm => &COOLWARM,
+      Colormap::Gray => &GRAY_MAP,
+    };
+    let t = if t.is_nan() { 0.0 } else { t.clamp(0.0, 1.0) };
+    let pos = t * (stops.len() - 1) as f32;
+    let i = (pos.floor() as usize).min(stops.len() - 2);
+    let f = pos - i as f32;
+    let lerp = |a: u8, b: u8| (a as f32 + (b as f32 - a as f32) * f).round() as u8;
+    let (a, b) = (stops[i], stops[i + 1]);
+    [lerp(a[0], b[0]), lerp(a[1], b[1]), lerp(a[2], b[2]), 255]
+  }
+}
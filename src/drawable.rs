@@ -1,4 +1,6 @@
-use tiny_skia::{Pixmap, Transform};
+use tiny_skia::{Mask, Pixmap, Transform};
+
+use crate::{polar::Polar, text_render::TextRender, view3d::View3D};
 
 pub struct Bound {
   pub x_min: f32,
@@ -6,10 +8,55 @@ pub struct Bound {
   pub y_min: f32,
   pub y_max: f32,
 }
+pub struct Bound3D {
+  pub x_min: f32,
+  pub x_max: f32,
+  pub y_min: f32,
+  pub y_max: f32,
+  pub z_min: f32,
+  pub z_max: f32,
+}
 pub trait Drawable {
-  fn draw(&self, pixmap: &mut Pixmap, ts: &Transform);
+  /// draw with the data transform `ts`, `mask` is the plot area unless `clip` is `false`
+  fn draw(&self, pixmap: &mut Pixmap, ts: &Transform, mask: Option<&Mask>);
   fn bound(&self) -> Option<Bound>;
   fn name(&self) -> String;
   fn get_color(&self) -> [u8; 4];
   fn set_color(&mut self, color: [u8; 4]);
+  /// whether the drawing is clipped to the plot area, annotations may draw over the margins
+  fn clip(&self) -> bool {
+    true
+  }
+  /// draw the text belonging to the drawable, called after every drawable has been drawn
+  /// * `axes_ts` maps fractions of the plot area, `(0, 0)` bottom left and `(1, 1)` top right
+  /// * `mask` is the plot area unless `clip` is `false`
+  fn draw_text(
+    &self, _pixmap: &mut Pixmap, _ts: &Transform, _axes_ts: &Transform, _mask: Option<&Mask>,
+    _tr: &TextRender,
+  ) {
+  }
+  /// draw into a polar axis, the x data is the angle in radians and the y data the radius
+  /// * `mask` is the disc of the axis unless `clip` is `false`
+  ///
+  /// drawables without a polar form are not drawn in a polar axis
+  fn draw_polar(&self, _pixmap: &mut Pixmap, _polar: &Polar, _mask: Option<&Mask>) {}
+  /// bound of the `(theta, r)` data in a polar axis, the radial limit fits its `y_max`
+  fn bound_polar(&self) -> Option<Bound> {
+    self.bound()
+  }
+  /// draw into a 3d axis, only drawables with a `bound_3d` are drawn there
+  /// * `mask` is the rectangle of the axis unless `clip` is `false`
+  fn draw_3d(&self, _pixmap: &mut Pixmap, _view: &View3D, _mask: Option<&Mask>) {}
+  /// bound of the `(x, y, z)` data of a 3d drawable
+  fn bound_3d(&self) -> Option<Bound3D> {
+    None
+  }
+  /// labels replacing the numeric ticks of the x axis, as `(position, label)`
+  fn x_labels(&self) -> Option<Vec<(f32, String)>> {
+    None
+  }
+  /// labels replacing the numeric ticks of the y axis, as `(position, label)`
+  fn y_labels(&self) -> Option<Vec<(f32, String)>> {
+    None
+  }
 }
@@ -1,4 +1,4 @@
-use std::{num::NonZeroU32, rc::Rc, vec};
+use std::{num::NonZeroU32, ops::Range, rc::Rc, vec};
 
 use winit::{
   application::ApplicationHandler,
@@ -9,11 +9,16 @@ use winit::{
   window::Window,
 };
 
-use tiny_skia::{Color, Pixmap};
+use tiny_skia::{Color, Paint, PathBuilder, Pixmap, Point, Rect, Stroke, Transform};
 
 use softbuffer::{Context, Surface};
 
-use crate::{axis::Axis, color, text_render::TextRender};
+use crate::{
+  axis::{Axis, Margins, Projection},
+  color,
+  gridspec::GridSpec,
+  text_render::{HAlign, TextRender, TextStyle},
+};
 
 pub struct Figure {
   window: Option<Rc<Window>>,
@@ -23,28 +28,61 @@ pub struct Figure {
   pixmap: Pixmap,
   tr: TextRender,
   axes: Vec<Axis>,
+  /// where each axis is placed
+  placements: Vec<Placement>,
+  /// `(parent, inset)` pairs of `indicate_inset_zoom`
+  inset_zooms: Vec<(usize, usize)>,
+  grid: GridSpec,
+  tight_layout: bool,
+  /// groups of axes sharing the x limit, and the y limit
+  x_groups: Vec<Vec<usize>>,
+  y_groups: Vec<Vec<usize>>,
   config: Config,
+
+  /// last cursor position in pixels
+  cursor: (f32, f32),
+  /// the axis panned (or rotated in 3d) by the mouse drag
+  dragging: Option<usize>,
 }
 
 pub struct Config {
   title: String,
   size: (u32, u32),
-  layout: (u32, u32),
 }
 impl Default for Config {
   fn default() -> Self {
     Self {
       title: String::from("Painter"),
       size: (600, 400),
-      layout: (1, 1),
     }
   }
 }
 
+/// Where an axis of a `Figure` is placed
+enum Placement {
+  /// rows and columns of the grid
+  Grid(Range<u32>, Range<u32>),
+  /// `[left, bottom, width, height]` in fractions of the figure, drawn above the grid
+  Rect([f32; 4]),
+}
+
+/// Handle of an `Axis` created by a `Figure`, see `Figure::axis`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AxisHandle(usize);
+
 impl Figure {
+  /// degrees the camera of a 3d axis turns per dragged pixel
+  const DRAG_SPEED: f32 = 0.5;
+  const TITLE_SIZE: f32 = 26.0;
+  /// pixels above the figure title
+  const TITLE_Y: f32 = 12.0;
+  /// scale of the limits per line scrolled up, below 1 zooms in
+  const ZOOM_STEP: f32 = 0.9;
+
   pub fn new(config: Config) -> Self {
     let (width, height) = config.size;
     let axes = vec![Axis::new(0., 0., (0., 0.))];
+    let placements = vec![Placement::Grid(0..1, 0..1)];
     Self {
       window: None,
       context: None,
@@ -53,7 +91,15 @@ impl Figure {
       tr: TextRender::new(),
       pixmap: Pixmap::new(width, height).unwrap(),
       axes,
+      placements,
+      inset_zooms: Vec::new(),
+      grid: GridSpec::new(1, 1),
+      tight_layout: false,
+      x_groups: Vec::new(),
+      y_groups: Vec::new(),
       config,
+      cursor: (0.0, 0.0),
+      dragging: None,
     }
   }
   pub fn show(&mut self) {
@@ -66,6 +112,11 @@ impl Figure {
   pub fn set_font(&mut self, family: &str) {
     self.tr.load_font(family);
   }
+  /// Sets the families tried in order for characters missing from the font of `set_font`, e.g.
+  /// a CJK font for labels mixing scripts. Other system fonts are tried after them.
+  pub fn set_font_fallbacks(&mut self, families: &[&str]) {
+    self.tr.set_fallbacks(families);
+  }
 
   fn resize(&mut self, size: PhysicalSize<u32>) {
     let w = size.width;
@@ -88,30 +139,353 @@ impl Figure {
     self.change_axis_size(size);
   }
   fn change_axis_size(&mut self, size: PhysicalSize<u32>) {
-    let w = size.width as f32;
-    let h = size.height as f32;
-
-    let (rows, cols) = self.config.layout;
-
-    let each_w = w / cols as f32;
-    let each_h = h / rows as f32;
-
+    // the tight layout keeps the figure title out of the first row
+    let top = if self.tight_layout {
+      Self::TITLE_Y + self.tr.measure(&self.config.title, Self::TITLE_SIZE).1
+    } else {
+      0.0
+    };
+    let (fig_w, fig_h) = (size.width as f32, size.height as f32);
     // 更新每个 Axis 的位置和大小
-    for (index, a) in self.axes.iter_mut().enumerate() {
-      let r = (index as u32) / cols;
-      let c = (index as u32) % cols;
-
-      // 更新 Axis 内部的 viewport 或位置属性
-      a.change_veiwport((c as f32 * each_w, r as f32 * each_h), (each_w, each_h));
+    for (a, placement) in self.axes.iter_mut().zip(&self.placements) {
+      match placement {
+        Placement::Grid(rows, cols) => {
+          let (x, y, w, h) = self.grid.cell_rect(rows, cols, (fig_w, fig_h - top));
+          a.change_veiwport((x, y + top), (w, h));
+        }
+        // figure fractions grow upward from the bottom left corner
+        Placement::Rect([l, b, w, h]) => {
+          a.change_veiwport((l * fig_w, (1.0 - b - h) * fig_h), (w * fig_w, h * fig_h));
+        }
+      }
+    }
+  }
+  /// Fits the margins of every axis to its measured text and keeps the title out of the grid.
+  ///
+  /// See `Axis::set_tight_layout`, axes added later follow the figure.
+  pub fn set_tight_layout(&mut self, tight: bool) {
+    self.tight_layout = tight;
+    for a in &mut self.axes {
+      a.set_tight_layout(tight);
     }
   }
-  pub fn add_subplot(&mut self, layout: (u32, u32)) {
-    self.config.layout = layout;
+  /// Replaces the axes by an equal grid of `(rows, cols)` subplots, in row-major order.
+  pub fn add_subplot(&mut self, layout: (u32, u32)) -> Vec<AxisHandle> {
+    let (rows, cols) = layout;
+    self.set_gridspec(GridSpec::new(rows, cols));
+    let (rows, cols) = (self.grid.rows(), self.grid.cols());
+
+    (0..rows * cols)
+      .map(|i| {
+        let (r, c) = (i / cols, i % cols);
+        self.add_subplot_span(r..r + 1, c..c + 1)
+      })
+      .collect()
+  }
+  /// Replaces the axes by an empty grid, fill it with `add_subplot_span`.
+  pub fn set_gridspec(&mut self, grid: GridSpec) {
+    self.grid = grid;
     self.axes.clear();
-    let (rows, cols) = self.config.layout;
-
-    for _ in 0..rows * cols {
-      self.axes.push(Axis::new(0.0, 0.0, (0.0, 0.0)));
+    self.placements.clear();
+    self.inset_zooms.clear();
+    self.x_groups.clear();
+    self.y_groups.clear();
+  }
+  /// Adds an axis covering a span of the grid.
+  ///
+  /// # Arguments
+  ///
+  /// * `rows` - The rows covered, e.g. `0..2` for the first two rows.
+  /// * `cols` - The columns covered, spans outside the grid are clamped to it.
+  pub fn add_subplot_span(&mut self, rows: Range<u32>, cols: Range<u32>) -> AxisHandle {
+    self.push_axis(Placement::Grid(rows, cols))
+  }
+  /// Adds an axis at an arbitrary rectangle, drawn above the grid subplots (e.g. an inset).
+  ///
+  /// # Arguments
+  ///
+  /// * `rect` - `[left, bottom, width, height]` in fractions of the figure, from the bottom left.
+  pub fn add_axes(&mut self, rect: [f32; 4]) -> AxisHandle {
+    self.push_axis(Placement::Rect(rect))
+  }
+  fn push_axis(&mut self, placement: Placement) -> AxisHandle {
+    let mut axis = Axis::new(0.0, 0.0, (0.0, 0.0));
+    axis.set_tight_layout(self.tight_layout);
+    self.axes.push(axis);
+    self.placements.push(placement);
+    let size = self.config.size;
+    self.change_axis_size(PhysicalSize::new(size.0, size.1));
+    AxisHandle(self.axes.len() - 1)
+  }
+  /// Draws the limits of `inset` as a rectangle on `parent`, with lines to the corners of `inset`.
+  pub fn indicate_inset_zoom(&mut self, parent: AxisHandle, inset: AxisHandle) {
+    self.inset_zooms.push((parent.0, inset.0));
+  }
+  /// axes in drawing order: the grid, then the rectangles
+  fn drawing_order(&self) -> Vec<usize> {
+    let (mut grid, rects): (Vec<usize>, Vec<usize>) =
+      (0..self.axes.len()).partition(|&i| matches!(self.placements[i], Placement::Grid(..)));
+    grid.extend(rects);
+    grid
+  }
+  /// the topmost axis containing the pixel `(x, y)` among those accepted by `filter`
+  fn axis_at(&self, x: f32, y: f32, filter: impl Fn(&Axis) -> bool) -> Option<usize> {
+    self
+      .drawing_order()
+      .into_iter()
+      .rev()
+      .find(|&i| filter(&self.axes[i]) && self.axes[i].contains(x, y))
+  }
+  fn draw_inset_zooms(&mut self) {
+    let [r, g, b, a] = color::get_fg();
+    let mut paint = Paint::default();
+    paint.set_color_rgba8(r, g, b, a);
+    paint.anti_alias = true;
+    let stroke = Stroke {
+      width: 1.0,
+      ..Stroke::default()
+    };
+    for &(parent, inset) in &self.inset_zooms {
+      let (Some(x_limit), Some(y_limit)) = (self.axes[inset].x_limit(), self.axes[inset].y_limit())
+      else {
+        continue;
+      };
+      let (Some(zoom), Some(target)) = (
+        self.axes[parent].data_rect(x_limit, y_limit),
+        self.axes[inset].plot_rect(),
+      ) else {
+        continue;
+      };
+      let path = PathBuilder::from_rect(zoom);
+      (self.pixmap).stroke_path(&path, &paint, &stroke, Transform::identity(), None);
+      // the connectors are lighter than the rectangle
+      let mut pb = PathBuilder::new();
+      for (from, to) in Self::connectors(zoom, target) {
+        pb.move_to(from.x, from.y);
+        pb.line_to(to.x, to.y);
+      }
+      let mut light = paint.clone();
+      light.set_color_rgba8(r, g, b, a / 2);
+      if let Some(path) = pb.finish() {
+        (self.pixmap).stroke_path(&path, &light, &stroke, Transform::identity(), None);
+      }
+    }
+  }
+  /// Lines up the plot areas of the grid in the tight layout: each side of an axis gets the
+  /// largest margin among the axes starting or ending on the same row or column.
+  fn align_grid_margins(&mut self) {
+    let measured: Vec<Option<Margins>> = self
+      .axes
+      .iter_mut()
+      .zip(&self.placements)
+      .map(|(a, placement)| match placement {
+        Placement::Grid(..) => a.tight_margins(&self.tr),
+        Placement::Rect(_) => None,
+      })
+      .collect();
+    let grid: Vec<(&Range<u32>, &Range<u32>, Margins)> = self
+      .placements
+      .iter()
+      .zip(&measured)
+      .filter_map(|(placement, m)| match (placement, m) {
+        (Placement::Grid(rows, cols), Some(m)) => Some((rows, cols, *m)),
+        _ => None,
+      })
+      .collect();
+    let aligned: Vec<Option<Margins>> = self
+      .placements
+      .iter()
+      .zip(&measured)
+      .map(|(placement, m)| {
+        let (Placement::Grid(rows, cols), Some(mut m)) = (placement, *m) else {
+          return None;
+        };
+        for (r, c, other) in &grid {
+          if r.start == rows.start {
+            m.top = m.top.max(other.top);
+          }
+          if r.end == rows.end {
+            m.bottom = m.bottom.max(other.bottom);
+          }
+          if c.start == cols.start {
+            m.left = m.left.max(other.left);
+          }
+          if c.end == cols.end {
+            m.right = m.right.max(other.right);
+          }
+        }
+        Some(m)
+      })
+      .collect();
+    for (a, m) in self.axes.iter_mut().zip(aligned) {
+      a.set_grid_margins(m);
+    }
+  }
+  /// draws the title, the axes and the inset zooms into the pixmap
+  fn draw_frame(&mut self, w: u32) {
+    // draw start
+    let bg = color::get_bg();
+    self
+      .pixmap
+      .fill(Color::from_rgba8(bg[0], bg[1], bg[2], bg[3]));
+
+    let style = TextStyle {
+      size: Self::TITLE_SIZE,
+      h_align: HAlign::Center,
+      ..TextStyle::default()
+    };
+    (self.tr).draw_styled(
+      &mut self.pixmap,
+      &self.config.title,
+      w as f32 / 2.0,
+      Self::TITLE_Y,
+      &style,
+      None,
+    );
+    // ================draw into pixmap====
+
+    //
+    // draw axes
+    self.align_grid_margins();
+    for i in self.drawing_order() {
+      // axes above the grid hide what is below them
+      if let (Placement::Rect(_), Some(rect)) = (&self.placements[i], self.axes[i].bounds()) {
+        let mut paint = Paint::default();
+        paint.set_color_rgba8(bg[0], bg[1], bg[2], bg[3]);
+        self
+          .pixmap
+          .fill_rect(rect, &paint, Transform::identity(), None);
+      }
+      self.axes[i].render(&mut self.pixmap, &self.tr);
+    }
+    self.draw_inset_zooms();
+  }
+  /// lines between matching corners of two rectangles that keep both rectangles on one side,
+  /// the outline of the zoom
+  fn connectors(from: Rect, to: Rect) -> Vec<(Point, Point)> {
+    let corners = |r: Rect| {
+      [
+        Point::from_xy(r.left(), r.top()),
+        Point::from_xy(r.right(), r.top()),
+        Point::from_xy(r.right(), r.bottom()),
+        Point::from_xy(r.left(), r.bottom()),
+      ]
+    };
+    let (a, b) = (corners(from), corners(to));
+    let all: Vec<Point> = a.iter().chain(b.iter()).cloned().collect();
+    (0..4)
+      .map(|k| (a[k], b[k]))
+      .filter(|(p, q)| {
+        let side = |c: &Point| (q.x - p.x) * (c.y - p.y) - (q.y - p.y) * (c.x - p.x);
+        let sides: Vec<f32> = all.iter().map(side).filter(|s| s.abs() > 1e-3).collect();
+        sides.iter().all(|s| *s > 0.0) || sides.iter().all(|s| *s < 0.0)
+      })
+      .collect()
+  }
+  /// The axis behind a handle returned by this figure.
+  pub fn axis(&mut self, handle: AxisHandle) -> &mut Axis {
+    &mut self.axes[handle.0]
+  }
+  /// Links the x limit of `axes`: they fit the union of their data and pan/zoom together.
+  ///
+  /// The x tick labels are hidden on every axis with another one of the group below it.
+  pub fn sharex(&mut self, axes: &[AxisHandle]) {
+    let group = Self::add_group(&mut self.x_groups, axes);
+    for &i in &group {
+      let Placement::Grid(rows, cols) = &self.placements[i] else {
+        continue;
+      };
+      let below = group.iter().any(|&j| match &self.placements[j] {
+        Placement::Grid(r, c) => r.start >= rows.end && c.start < cols.end && cols.start < c.end,
+        Placement::Rect(_) => false,
+      });
+      if below {
+        self.axes[i].set_x_tick_labels(false);
+      }
+    }
+  }
+  /// Links the y limit of `axes`: they fit the union of their data and pan/zoom together.
+  ///
+  /// The y tick labels are hidden on every axis with another one of the group to its left.
+  pub fn sharey(&mut self, axes: &[AxisHandle]) {
+    let group = Self::add_group(&mut self.y_groups, axes);
+    for &i in &group {
+      let Placement::Grid(rows, cols) = &self.placements[i] else {
+        continue;
+      };
+      let left = group.iter().any(|&j| match &self.placements[j] {
+        Placement::Grid(r, c) => c.end <= cols.start && r.start < rows.end && rows.start < r.end,
+        Placement::Rect(_) => false,
+      });
+      if left {
+        self.axes[i].set_y_tick_labels(false);
+      }
+    }
+  }
+  /// merge `axes` and every group overlapping them into one group
+  fn add_group(groups: &mut Vec<Vec<usize>>, axes: &[AxisHandle]) -> Vec<usize> {
+    let mut group: Vec<usize> = axes.iter().map(|h| h.0).collect();
+    groups.retain(|g| {
+      let overlaps = g.iter().any(|i| group.contains(i));
+      if overlaps {
+        group.extend(g.iter().filter(|i| !group.contains(i)).collect::<Vec<_>>());
+      }
+      !overlaps
+    });
+    group.sort_unstable();
+    group.dedup();
+    groups.push(group.clone());
+    group
+  }
+  /// give the shared axes without a limit the union of the bounds of their group
+  fn sync_shared_limits(&mut self) {
+    for (groups, is_x) in [(&self.x_groups, true), (&self.y_groups, false)] {
+      for group in groups {
+        let limit = |a: &Axis| if is_x { a.x_limit() } else { a.y_limit() };
+        if group.iter().all(|&i| limit(&self.axes[i]).is_some()) {
+          continue;
+        }
+        let shared = group
+          .iter()
+          .find_map(|&i| limit(&self.axes[i]))
+          .or_else(|| {
+            let (lo, hi) = group
+              .iter()
+              .filter_map(|&i| self.axes[i].data_bound())
+              .map(|b| {
+                if is_x {
+                  (b.x_min, b.x_max)
+                } else {
+                  (b.y_min, b.y_max)
+                }
+              })
+              .fold((f32::INFINITY, f32::NEG_INFINITY), |(lo, hi), (l, h)| {
+                (lo.min(l), hi.max(h))
+              });
+            (lo <= hi).then(|| Axis::padded_limit(lo, hi))
+          });
+        for &i in group {
+          if is_x {
+            self.axes[i].set_x_limit(shared);
+          } else {
+            self.axes[i].set_y_limit(shared);
+          }
+        }
+      }
+    }
+  }
+  /// copy the limits of axis `index` to the axes sharing them
+  fn propagate_limits(&mut self, index: usize) {
+    let (x, y) = (self.axes[index].x_limit(), self.axes[index].y_limit());
+    for group in self.x_groups.iter().filter(|g| g.contains(&index)) {
+      for &i in group {
+        self.axes[i].set_x_limit(x);
+      }
+    }
+    for group in self.y_groups.iter().filter(|g| g.contains(&index)) {
+      for &i in group {
+        self.axes[i].set_y_limit(y);
+      }
     }
   }
   pub fn nth(&mut self, index: usize) -> Option<&mut Axis> {
@@ -140,21 +514,8 @@ impl ApplicationHandler for Figure {
       let size = window.as_ref().inner_size();
       self.config.size = (size.width, size.height);
 
-      // if have subplot, create axes, if not, create one
-      let (rows, cols) = self.config.layout;
       let (total_w, total_h) = self.config.size;
-      let each_w = (total_w / cols) as f32;
-      let each_h = (total_h / rows) as f32;
-
-      for r in 0..rows {
-        for c in 0..cols {
-          // 计算每个子图的左上角坐标
-          let x = c as f32 * each_w;
-          let y = r as f32 * each_h;
-          self.axes[(r * rows + c) as usize].change_veiwport((x, y), (each_w, each_h));
-          // local_axes.push(Axis::new(x, y, (each_w, each_h)));
-        }
-      }
+      self.change_axis_size(size);
       let pixmap = Pixmap::new(total_w, total_h).unwrap();
       self.pixmap = pixmap;
       self.window = Some(window);
@@ -170,7 +531,8 @@ impl ApplicationHandler for Figure {
     match event {
       WindowEvent::CloseRequested => event_loop.exit(),
       WindowEvent::RedrawRequested => {
-        let (Some(window), Some(surface)) = (&self.window, &mut self.surface) else {
+        self.sync_shared_limits();
+        let Some(window) = &self.window else {
           return;
         };
 
@@ -181,34 +543,11 @@ impl ApplicationHandler for Figure {
           return;
         }
 
-        // draw start
-        let bg = color::get_bg();
-        self
-          .pixmap
-          .fill(Color::from_rgba8(bg[0], bg[1], bg[2], bg[3]));
-
-        let mid = w / 2;
-        let mid_title_len = self.config.title.len() as u32 / 2;
-        const TITLE_SIZE: u32 = 16;
-        let mid = mid - mid_title_len * TITLE_SIZE;
-        let [r, g, b, a] = color::get_fg();
-        self.tr.draw(
-          &mut self.pixmap,
-          &self.config.title,
-          mid as f32,
-          12.,
-          26.,
-          Color::from_rgba8(r, g, b, a),
-        );
-        // ================draw into pixmap====
-
-        //
-        // draw axes
-        for a in &mut self.axes {
-          // println!("{} {} {:?}", a.x, a.y, a.veiwport);
-          a.render(&mut self.pixmap, &self.tr);
-        }
+        self.draw_frame(w);
         //===========pixmap to buffer ===============
+        let Some(surface) = &mut self.surface else {
+          return;
+        };
         let mut buffer = match surface.buffer_mut() {
           Ok(b) => b,
           Err(e) => {
@@ -235,6 +574,54 @@ impl ApplicationHandler for Figure {
         }
         self.resize(size)
       }
+      // drag a cartesian axis to pan it, a 3d axis to rotate its camera
+      WindowEvent::MouseInput {
+        state,
+        button: MouseButton::Left,
+        ..
+      } => {
+        let (x, y) = self.cursor;
+        self.dragging = match state {
+          ElementState::Pressed => self.axis_at(x, y, |_| true),
+          ElementState::Released => None,
+        };
+      }
+      WindowEvent::CursorMoved { position, .. } => {
+        let (x, y) = (position.x as f32, position.y as f32);
+        let (dx, dy) = (x - self.cursor.0, y - self.cursor.1);
+        self.cursor = (x, y);
+        let Some(index) = self.dragging else {
+          return;
+        };
+        let axis = &mut self.axes[index];
+        match axis.projection() {
+          Projection::Cartesian => {
+            axis.pan(dx, dy);
+            self.propagate_limits(index);
+          }
+          Projection::ThreeD => axis.rotate(dy * Self::DRAG_SPEED, -dx * Self::DRAG_SPEED),
+          Projection::Polar => return,
+        }
+        if let Some(window) = &self.window {
+          window.request_redraw();
+        }
+      }
+      // the wheel zooms a cartesian axis around the cursor
+      WindowEvent::MouseWheel { delta, .. } => {
+        let lines = match delta {
+          MouseScrollDelta::LineDelta(_, y) => y,
+          MouseScrollDelta::PixelDelta(p) => p.y as f32 / 50.0,
+        };
+        let (x, y) = self.cursor;
+        let Some(index) = self.axis_at(x, y, |a| a.projection() == Projection::Cartesian) else {
+          return;
+        };
+        self.axes[index].zoom(Self::ZOOM_STEP.powf(lines), (x, y));
+        self.propagate_limits(index);
+        if let Some(window) = &self.window {
+          window.request_redraw();
+        }
+      }
       WindowEvent::KeyboardInput {
         event:
           KeyEvent {
@@ -252,3 +639,82 @@ impl ApplicationHandler for Figure {
     }
   }
 }
+
+#[test]
+fn test_sharex() {
+  use crate::primitive::{self, Curve};
+
+  let mut figure = Figure::new(Config::default());
+  let handles = figure.add_subplot((2, 1));
+  for (h, range) in handles.iter().zip([(0.0, 5.0), (2.0, 10.0)]) {
+    let mut curve = Curve::new("c".to_string(), primitive::Config::default());
+    curve.set_data(&[range.0, range.1], &[0.0, 1.0]);
+    figure.axis(*h).add(Box::new(curve));
+  }
+  figure.sharex(&handles);
+  figure.sync_shared_limits();
+
+  let expected = Some(Axis::padded_limit(0.0, 10.0));
+  assert_eq!(figure.axes[0].x_limit(), expected);
+  assert_eq!(figure.axes[1].x_limit(), expected);
+  // the y limits stay independent
+  assert_eq!(figure.axes[0].y_limit(), None);
+
+  figure.change_axis_size(PhysicalSize::new(600, 400));
+  figure.axes[1].pan(-10.0, 0.0);
+  figure.propagate_limits(1);
+  assert_ne!(figure.axes[1].x_limit(), expected);
+  assert_eq!(figure.axes[0].x_limit(), figure.axes[1].x_limit());
+
+  // shared both ways, only the bottom row keeps x labels and the left column y labels
+  let mut figure = Figure::new(Config::default());
+  let handles = figure.add_subplot((2, 2));
+  figure.sharex(&handles);
+  figure.sharey(&handles);
+  let shown: Vec<_> = figure.axes.iter().map(|a| a.shows_tick_labels()).collect();
+  assert_eq!(
+    shown,
+    [(false, true), (false, false), (true, true), (true, false)]
+  );
+}
+
+#[test]
+fn test_inset_connectors() {
+  // a zoom box below and left of the inset is joined by its top left and bottom right corners
+  let zoom = Rect::from_ltrb(10.0, 60.0, 20.0, 70.0).unwrap();
+  let inset = Rect::from_ltrb(50.0, 0.0, 90.0, 30.0).unwrap();
+  let lines = Figure::connectors(zoom, inset);
+  assert_eq!(lines.len(), 2);
+  assert_eq!(lines[0].0, Point::from_xy(10.0, 60.0));
+  assert_eq!(lines[1].1, Point::from_xy(90.0, 30.0));
+}
+
+#[test]
+fn test_grid_margins() {
+  use crate::{
+    ScaleStrategy,
+    primitive::{self, Curve},
+  };
+
+  let mut figure = Figure::new(Config::default());
+  figure.set_tight_layout(true);
+  let handles = figure.add_subplot((2, 2));
+  for (h, y_max) in handles.iter().zip([250000.0, 1.0, 1.0, 1.0]) {
+    let mut curve = Curve::new("c".to_string(), primitive::Config::default());
+    curve.set_data(&[0.0, 1.0], &[0.0, y_max]);
+    figure.axis(*h).add(Box::new(curve));
+    figure.axis(*h).set_strategy(ScaleStrategy::Stretch);
+  }
+  figure.axis(handles[1]).set_title("title");
+  let (w, h) = figure.config.size;
+  figure.change_axis_size(PhysicalSize::new(w, h));
+  figure.draw_frame(w);
+
+  let rects: Vec<Rect> = figure.axes.iter().map(|a| a.plot_rect().unwrap()).collect();
+  // the wide labels of the top left axis move the left column, the title the top row
+  assert_eq!(rects[0].left(), rects[2].left());
+  assert_eq!(rects[0].top(), rects[1].top());
+  assert_eq!(rects[2].top(), rects[3].top());
+  assert!(rects[0].left() - figure.axes[0].bounds().unwrap().left() > 50.0);
+  assert!(rects[1].left() - figure.axes[1].bounds().unwrap().left() < 50.0);
+}
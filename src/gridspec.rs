@@ -0,0 +1,86 @@
+use std::ops::Range;
+
+/// Grid the subplots of a `Figure` are placed on
+/// * a subplot covers a span of rows and columns, see `Figure::add_subplot_span`
+/// * the size of each row and column follows its ratio, the spacing is in pixels
+#[derive(Debug, Clone)]
+pub struct GridSpec {
+  rows: u32,
+  cols: u32,
+  width_ratios: Vec<f32>,
+  height_ratios: Vec<f32>,
+  /// pixels between two columns
+  wspace: f32,
+  /// pixels between two rows
+  hspace: f32,
+}
+
+impl GridSpec {
+  pub fn new(rows: u32, cols: u32) -> Self {
+    let (rows, cols) = (rows.max(1), cols.max(1));
+    Self {
+      rows,
+      cols,
+      width_ratios: vec![1.0; cols as usize],
+      height_ratios: vec![1.0; rows as usize],
+      wspace: 0.0,
+      hspace: 0.0,
+    }
+  }
+  pub fn rows(&self) -> u32 {
+    self.rows
+  }
+  pub fn cols(&self) -> u32 {
+    self.cols
+  }
+  /// Sets the relative widths of the columns, missing ratios are `1.0`.
+  pub fn set_width_ratios(&mut self, ratios: &[f32]) {
+    self.width_ratios = Self::ratios(ratios, self.cols);
+  }
+  /// Sets the relative heights of the rows from top to bottom, missing ratios are `1.0`.
+  pub fn set_height_ratios(&mut self, ratios: &[f32]) {
+    self.height_ratios = Self::ratios(ratios, self.rows);
+  }
+  /// Sets the space between the columns and between the rows in pixels, default is `0.0`.
+  pub fn set_spacing(&mut self, wspace: f32, hspace: f32) {
+    self.wspace = wspace.max(0.0);
+    self.hspace = hspace.max(0.0);
+  }
+
+  fn ratios(ratios: &[f32], n: u32) -> Vec<f32> {
+    (0..n as usize)
+      .map(|i| ratios.get(i).cloned().filter(|r| *r > 0.0).unwrap_or(1.0))
+      .collect()
+  }
+  /// pixel `(start, length)` of the tracks in `span` along a side of `total` pixels
+  fn extent(ratios: &[f32], space: f32, total: f32, span: &Range<u32>) -> (f32, f32) {
+    let n = ratios.len();
+    let sum: f32 = ratios.iter().sum();
+    let unit = (total - space * (n - 1) as f32).max(0.0) / sum;
+    let start = span.start.min(n as u32 - 1) as usize;
+    let end = (span.end as usize).clamp(start + 1, n);
+    let offset = ratios[..start].iter().sum::<f32>() * unit + space * start as f32;
+    let length = ratios[start..end].iter().sum::<f32>() * unit + space * (end - start - 1) as f32;
+    (offset, length)
+  }
+  /// pixel `(x, y, width, height)` of a span of rows and columns in a figure of `size`
+  pub(crate) fn cell_rect(
+    &self, rows: &Range<u32>, cols: &Range<u32>, size: (f32, f32),
+  ) -> (f32, f32, f32, f32) {
+    let (x, w) = Self::extent(&self.width_ratios, self.wspace, size.0, cols);
+    let (y, h) = Self::extent(&self.height_ratios, self.hspace, size.1, rows);
+    (x, y, w, h)
+  }
+}
+
+#[test]
+fn test_cell_rect() {
+  let mut grid = GridSpec::new(2, 3);
+  grid.set_width_ratios(&[2.0, 1.0, 1.0]);
+  grid.set_spacing(10.0, 20.0);
+  // 420 - 2 * 10 = 400 pixels for 4 ratio units
+  let (x, y, w, h) = grid.cell_rect(&(0..2), &(0..2), (420.0, 220.0));
+  assert_eq!((x, y, w, h), (0.0, 0.0, 310.0, 220.0));
+  let (x, y, w, h) = grid.cell_rect(&(1..2), &(2..3), (420.0, 220.0));
+  assert_eq!((x, y, w, h), (320.0, 120.0, 100.0, 100.0));
+}
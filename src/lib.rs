@@ -2,10 +2,17 @@ mod axis;
 mod color;
 mod drawable;
 mod figure;
+mod gridspec;
+mod polar;
 pub mod primitive;
 mod text_render;
 pub mod utils;
+mod view3d;
 
-pub use axis::ScaleStrategy;
+pub use axis::{Projection, ScaleStrategy};
+pub use color::Colormap;
 pub use figure::Config;
-pub use figure::Figure;
+pub use figure::{AxisHandle, Figure};
+pub use gridspec::GridSpec;
+pub use polar::{AngleUnit, ThetaDirection, ThetaZero};
+pub use text_render::{TextMetrics, TextRender, TextStyle};
@@ -0,0 +1,161 @@
+use std::f32::consts::{FRAC_PI_2, PI, TAU};
+
+use tiny_skia::{PathBuilder, Point};
+
+/// Unit of the angle labels of a polar axis, data angles are always radians
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum AngleUnit {
+  #[default]
+  Degrees,
+  Radians,
+}
+
+/// Where the angle `0` points in a polar axis
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum ThetaZero {
+  #[default]
+  East,
+  North,
+  West,
+  South,
+}
+
+/// Direction in which the angle grows in a polar axis
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum ThetaDirection {
+  #[default]
+  CounterClockwise,
+  Clockwise,
+}
+
+/// Mapping of `(theta, r)` data into pixels, handed to `Drawable::draw_polar`
+pub struct Polar {
+  center: Point,
+  /// pixels of the outer ring
+  radius: f32,
+  r_min: f32,
+  r_max: f32,
+  /// screen angle of `theta = 0`, counter-clockwise from east
+  zero: f32,
+  /// `1.0` counter-clockwise, `-1.0` clockwise
+  sense: f32,
+}
+
+impl Polar {
+  /// largest angle between two points of an arc
+  const ARC_STEP: f32 = TAU / 180.0;
+
+  pub(crate) fn new(
+    center: Point, radius: f32, r_limit: (f32, f32), zero: ThetaZero, direction: ThetaDirection,
+  ) -> Self {
+    let zero = match zero {
+      ThetaZero::East => 0.0,
+      ThetaZero::North => FRAC_PI_2,
+      ThetaZero::West => PI,
+      ThetaZero::South => PI + FRAC_PI_2,
+    };
+    let sense = match direction {
+      ThetaDirection::CounterClockwise => 1.0,
+      ThetaDirection::Clockwise => -1.0,
+    };
+    Self {
+      center,
+      radius,
+      r_min: r_limit.0,
+      r_max: r_limit.1,
+      zero,
+      sense,
+    }
+  }
+  /// pixel position of `(theta, r)`, values below the inner limit collapse to the center
+  ///
+  /// values above the outer limit land outside the circle, the axis clips them to its disc
+  pub(crate) fn map(&self, theta: f32, r: f32) -> Point {
+    let range = (self.r_max - self.r_min).max(1e-6);
+    let rho = ((r - self.r_min) / range).max(0.0) * self.radius;
+    let a = self.zero + self.sense * theta;
+    Point::from_xy(self.center.x + rho * a.cos(), self.center.y - rho * a.sin())
+  }
+  /// append `points` as a polyline, a straight segment in `(theta, r)` becomes an arc or spiral
+  pub(crate) fn push_polyline(&self, pb: &mut PathBuilder, points: &[(f32, f32)]) {
+    let Some(&(theta, r)) = points.first() else {
+      return;
+    };
+    let p = self.map(theta, r);
+    pb.move_to(p.x, p.y);
+    for w in points.windows(2) {
+      let ((t0, r0), (t1, r1)) = (w[0], w[1]);
+      let steps = ((t1 - t0).abs() / Self::ARC_STEP).ceil().max(1.0) as usize;
+      for k in 1..=steps {
+        let f = k as f32 / steps as f32;
+        let p = self.map(t0 + (t1 - t0) * f, r0 + (r1 - r0) * f);
+        pb.line_to(p.x, p.y);
+      }
+    }
+  }
+  /// append the closed annular sector between two angles and two radii
+  pub(crate) fn push_wedge(&self, pb: &mut PathBuilder, theta: (f32, f32), r: (f32, f32)) {
+    let (t0, t1) = theta;
+    let (r0, r1) = r;
+    self.push_polyline(pb, &[(t0, r0), (t0, r1), (t1, r1), (t1, r0), (t0, r0)]);
+    pb.close();
+  }
+}
+
+/// label of an angle given in degrees
+/// * `symbols` - use `°` and `π`, otherwise plain numbers and `pi` for fonts without them
+pub(crate) fn angle_label(degrees: i32, unit: AngleUnit, symbols: bool) -> String {
+  match unit {
+    AngleUnit::Degrees if symbols => format!("{}°", degrees),
+    AngleUnit::Degrees => degrees.to_string(),
+    AngleUnit::Radians => {
+      // multiples of π/180, reduced like 2π/3
+      let gcd = |mut a: i32, mut b: i32| {
+        while b != 0 {
+          (a, b) = (b, a % b);
+        }
+        a.abs()
+      };
+      if degrees == 0 {
+        return "0".to_string();
+      }
+      let pi = if symbols { "π" } else { "pi" };
+      let g = gcd(degrees, 180);
+      let (num, den) = (degrees / g, 180 / g);
+      match (num, den) {
+        (1, 1) => pi.to_string(),
+        (n, 1) => format!("{}{}", n, pi),
+        (1, d) => format!("{}/{}", pi, d),
+        (n, d) => format!("{}{}/{}", n, pi, d),
+      }
+    }
+  }
+}
+
+#[test]
+fn test_polar_map() {
+  let center = Point::from_xy(100.0, 100.0);
+  let east = Polar::new(
+    center,
+    50.0,
+    (0.0, 10.0),
+    ThetaZero::East,
+    ThetaDirection::CounterClockwise,
+  );
+  let p = east.map(FRAC_PI_2, 10.0);
+  assert!((p.x - 100.0).abs() < 1e-3 && (p.y - 50.0).abs() < 1e-3);
+
+  // compass style: 0 at the top, growing clockwise
+  let compass = Polar::new(
+    center,
+    50.0,
+    (0.0, 10.0),
+    ThetaZero::North,
+    ThetaDirection::Clockwise,
+  );
+  let p = compass.map(FRAC_PI_2, 5.0);
+  assert!((p.x - 125.0).abs() < 1e-3 && (p.y - 100.0).abs() < 1e-3);
+
+  assert_eq!(angle_label(120, AngleUnit::Radians, true), "2π/3");
+  assert_eq!(angle_label(180, AngleUnit::Radians, false), "pi");
+}
@@ -0,0 +1,256 @@
+use tiny_skia::{FillRule, Mask, PathBuilder, Pixmap, Point, Transform};
+
+use crate::{
+  color,
+  drawable::{Bound, Drawable},
+  primitive::{
+    config::Config,
+    text::{Coords, Text},
+    vector_field::push_arrow_head,
+  },
+  text_render::{HAlign, TextRender, VAlign},
+};
+
+/// Path of the arrow of an `Annotation`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ArrowStyle {
+  Straight,
+  /// quadratic curve, the control point is moved from the middle by this fraction of the length,
+  /// positive bends to the left of the direction from the text to the target
+  Curved(f32),
+}
+
+/// Head at the target end of the arrow of an `Annotation`
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum ArrowHead {
+  /// filled triangle
+  #[default]
+  Triangle,
+  /// two lines
+  Open,
+  /// filled dot on the target
+  Circle,
+  /// no head, only the line
+  None,
+}
+
+/// Text with an arrow pointing at a target
+/// * the text and the target are placed in their own coordinates, see `Coords`
+/// * the arrow starts at the edge of the text box and stops a little before the target
+/// * `config` styles the arrow, its color is also the color of the text, the foreground when unset
+pub struct Annotation {
+  label: Text,
+  target: (f32, f32),
+  target_coords: Coords,
+  style: ArrowStyle,
+  head: ArrowHead,
+  /// length of the head in pixels
+  head_size: f32,
+  /// pixels left between the tip and the target
+  shrink: f32,
+  clip: bool,
+  config: Config,
+}
+
+impl Annotation {
+  pub fn new(text: String, mut config: Config) -> Self {
+    if config.color == [0, 0, 0, 0] {
+      config.color = color::get_fg();
+    }
+    let mut label = Text::new(text, config);
+    label.set_align(HAlign::Center, VAlign::Center);
+    Self {
+      label,
+      target: (0.0, 0.0),
+      target_coords: Coords::Data,
+      style: ArrowStyle::Straight,
+      head: ArrowHead::default(),
+      head_size: 8.0,
+      shrink: 2.0,
+      clip: false,
+      config,
+    }
+  }
+  /// Sets the point the arrow points at.
+  pub fn set_target(&mut self, x: f32, y: f32, coords: Coords) {
+    self.target = (x, y);
+    self.target_coords = coords;
+  }
+  /// Sets the position of the text, centered on it unless changed through `label`.
+  pub fn set_text_position(&mut self, x: f32, y: f32, coords: Coords) {
+    self.label.set_position(x, y, coords);
+  }
+  /// The text of the annotation, to change its alignment, rotation, font size or box.
+  pub fn label(&mut self) -> &mut Text {
+    &mut self.label
+  }
+  /// Sets the path and the head of the arrow, default is a straight line with a triangle.
+  pub fn set_arrow(&mut self, style: ArrowStyle, head: ArrowHead) {
+    self.style = style;
+    self.head = head;
+  }
+  /// Sets the length of the head in pixels, default is `8.0`.
+  pub fn set_head_size(&mut self, size: f32) {
+    self.head_size = size.max(0.0);
+  }
+  /// Hides the annotation when the target leaves the plot area and clips the arrow to it,
+  /// default is `false`.
+  pub fn set_clip(&mut self, clip: bool) {
+    self.clip = clip;
+  }
+
+  /// control point of the arrow from `p` to `q`
+  fn control(&self, p: Point, q: Point) -> Point {
+    let mid = Point::from_xy((p.x + q.x) * 0.5, (p.y + q.y) * 0.5);
+    match self.style {
+      ArrowStyle::Straight => mid,
+      ArrowStyle::Curved(rad) => {
+        Point::from_xy(mid.x + rad * (q.y - p.y), mid.y - rad * (q.x - p.x))
+      }
+    }
+  }
+  /// pixels of `(start, control, tip)` of the arrow from the text box to the target
+  fn arrow(&self, text: Point, target: Point, tr: &TextRender) -> Option<(Point, Point, Point)> {
+    let rect = self.label.rect(text, tr)?;
+    let (half_w, half_h) = (rect.width() / 2.0, rect.height() / 2.0);
+    let center = Point::from_xy(rect.x() + half_w, rect.y() + half_h);
+    // leave the text box toward the control point
+    let ctrl = self.control(center, target);
+    let (dx, dy) = (ctrl.x - center.x, ctrl.y - center.y);
+    let t = (half_w / dx.abs()).min(half_h / dy.abs());
+    if !t.is_finite() || t >= 1.0 {
+      return None;
+    }
+    let start = Point::from_xy(center.x + dx * t, center.y + dy * t);
+    let ctrl = self.control(start, target);
+    // stop before the target, along the end of the curve
+    let (ex, ey) = (target.x - ctrl.x, target.y - ctrl.y);
+    let len = ex.hypot(ey);
+    if len <= self.shrink {
+      return None;
+    }
+    let tip = Point::from_xy(
+      target.x - ex / len * self.shrink,
+      target.y - ey / len * self.shrink,
+    );
+    Some((start, ctrl, tip))
+  }
+  fn draw_arrow(
+    &self, pixmap: &mut Pixmap, (start, ctrl, tip): (Point, Point, Point), mask: Option<&Mask>,
+  ) {
+    let dir = Point::from_xy(tip.x - ctrl.x, tip.y - ctrl.y);
+    let len = dir.x.hypot(dir.y);
+    let head = self.head_size;
+    // end the shaft inside a filled head so the line cap doesn't poke out of the tip
+    let end = match self.head {
+      ArrowHead::Triangle if len > 0.0 => Point::from_xy(
+        tip.x - dir.x / len * head * 0.5,
+        tip.y - dir.y / len * head * 0.5,
+      ),
+      _ => tip,
+    };
+    let paint = self.config.paint();
+    let stroke = self.config.stroke();
+
+    let mut pb = PathBuilder::new();
+    pb.move_to(start.x, start.y);
+    match self.style {
+      ArrowStyle::Straight => pb.line_to(end.x, end.y),
+      ArrowStyle::Curved(_) => pb.quad_to(ctrl.x, ctrl.y, end.x, end.y),
+    }
+    if self.head == ArrowHead::Open && len > 0.0 {
+      let (ux, uy) = (dir.x / len, dir.y / len);
+      let (bx, by) = (tip.x - ux * head, tip.y - uy * head);
+      let half_w = head * 0.5;
+      pb.move_to(bx - uy * half_w, by + ux * half_w);
+      pb.line_to(tip.x, tip.y);
+      pb.line_to(bx + uy * half_w, by - ux * half_w);
+    }
+    if let Some(path) = pb.finish() {
+      pixmap.stroke_path(&path, &paint, &stroke, Transform::identity(), mask);
+    }
+
+    let mut pb = PathBuilder::new();
+    match self.head {
+      ArrowHead::Triangle => push_arrow_head(&mut pb, tip, dir, head),
+      ArrowHead::Circle => pb.push_circle(tip.x, tip.y, head * 0.3),
+      ArrowHead::Open | ArrowHead::None => {}
+    }
+    if let Some(path) = pb.finish() {
+      pixmap.fill_path(
+        &path,
+        &paint,
+        FillRule::Winding,
+        Transform::identity(),
+        mask,
+      );
+    }
+  }
+}
+
+/// whether the pixel at `p` is inside `mask`
+fn covers(mask: &Mask, p: Point) -> bool {
+  let (x, y) = (p.x.round(), p.y.round());
+  if x < 0.0 || y < 0.0 || x >= mask.width() as f32 || y >= mask.height() as f32 {
+    return false;
+  }
+  mask.data()[y as usize * mask.width() as usize + x as usize] > 0
+}
+
+impl Drawable for Annotation {
+  // drawn over the other drawables in `draw_text`
+  fn draw(&self, _pixmap: &mut Pixmap, _ts: &Transform, _mask: Option<&Mask>) {}
+  fn draw_text(
+    &self, pixmap: &mut Pixmap, ts: &Transform, axes_ts: &Transform, mask: Option<&Mask>,
+    tr: &TextRender,
+  ) {
+    if self.config.is_hidden {
+      return;
+    }
+    let (x, y) = self.target;
+    let target = self.target_coords.map(x, y, ts, axes_ts);
+    let text = self.label.anchor(ts, axes_ts);
+    if mask.is_some_and(|mask| !covers(mask, target)) {
+      return;
+    }
+    if let Some(arrow) = self.arrow(text, target, tr) {
+      self.draw_arrow(pixmap, arrow, mask);
+    }
+    self.label.draw_at(pixmap, text, None, tr);
+  }
+  fn clip(&self) -> bool {
+    self.clip
+  }
+  // placed by the user, the limits come from the data
+  fn bound(&self) -> Option<Bound> {
+    None
+  }
+  fn name(&self) -> String {
+    self.label.name()
+  }
+  fn get_color(&self) -> [u8; 4] {
+    self.config.color
+  }
+  fn set_color(&mut self, color: [u8; 4]) {
+    self.config.color = color;
+    self.label.set_color(color);
+  }
+}
+
+#[test]
+fn test_annotation_arrow() {
+  let tr = TextRender::new();
+  let mut note = Annotation::new("peak".to_string(), Config::default());
+  let text = Point::from_xy(100.0, 100.0);
+  let target = Point::from_xy(200.0, 100.0);
+  let box_right = note.label.rect(text, &tr).unwrap().right();
+  // leaves the box on its right edge and stops `shrink` pixels before the target
+  let (start, _, tip) = note.arrow(text, target, &tr).unwrap();
+  assert!((start.x - box_right).abs() < 1e-3 && (start.y - 100.0).abs() < 1e-3);
+  assert!((tip.x - 198.0).abs() < 1e-3);
+
+  // a positive curve bends to the left, up on the screen
+  note.set_arrow(ArrowStyle::Curved(0.3), ArrowHead::Open);
+  let (start, ctrl, _) = note.arrow(text, target, &tr).unwrap();
+  assert!(ctrl.y < 100.0 && start.y < 100.0);
+}
@@ -1,7 +1,8 @@
-use tiny_skia::{FillRule, Paint, PathBuilder, Point, Stroke, Transform};
+use tiny_skia::{FillRule, Mask, PathBuilder, Point, Transform};
 
 use crate::{
   drawable::{Bound, Drawable},
+  polar::Polar,
   primitive::Config,
 };
 
@@ -10,12 +11,21 @@ pub enum AreaType {
   Line,
   Step,
 }
+/// The lower boundary of the filled area
+#[derive(Debug)]
+enum Baseline {
+  Constant(f32),
+  /// a second series, indexed like `y`
+  Series(Vec<f32>),
+}
 pub struct Area {
   name: String,
   x_edge: Vec<f32>,
   y_value: Vec<f32>,
 
   area_type: AreaType,
+  baseline: Baseline,
+  fill_where: Option<Box<dyn Fn(f32, f32) -> bool>>,
 
   config: Config,
 }
@@ -34,6 +44,8 @@ impl Area {
       y_value: Vec::new(),
       config,
       area_type: AreaType::Step,
+      baseline: Baseline::Constant(0.0),
+      fill_where: None,
     }
   }
   /// Adds data to the existing x and y vectors.
@@ -120,16 +132,103 @@ impl Area {
   pub fn change_area_type(&mut self, area_type: AreaType) {
     self.area_type = area_type;
   }
+  /// Fills down to a constant value instead of `0.0`.
+  ///
+  /// # Arguments
+  ///
+  /// * `baseline` - The y value of the lower boundary.
+  pub fn set_baseline(&mut self, baseline: f32) {
+    self.baseline = Baseline::Constant(baseline);
+  }
+  /// Fills between `y` and a second series, e.g. for confidence bands.
+  ///
+  /// # Arguments
+  ///
+  /// * `lower` - Slice of y coordinates of the lower boundary, indexed like `y`.
+  pub fn set_lower(&mut self, lower: &[f32]) {
+    self.baseline = Baseline::Series(lower.to_vec());
+  }
+  /// Only fills where `f(y, lower)` is true.
+  ///
+  /// In `AreaType::Line` the fill stops at the interpolated crossing of both boundaries.
+  ///
+  /// # Arguments
+  ///
+  /// * `f` - Predicate on the upper and the lower value at each point.
+  pub fn set_where(&mut self, f: impl Fn(f32, f32) -> bool + 'static) {
+    self.fill_where = Some(Box::new(f));
+  }
+  fn lower(&self, i: usize) -> f32 {
+    match &self.baseline {
+      Baseline::Constant(v) => *v,
+      Baseline::Series(s) => s.get(i).cloned().unwrap_or(0.0),
+    }
+  }
+  fn is_filled(&self, upper: f32, lower: f32) -> bool {
+    self.fill_where.as_ref().is_none_or(|f| f(upper, lower))
+  }
+  /// split the line into the regions to fill, as `(x, upper, lower)` points
+  fn line_regions(&self) -> Vec<Vec<(f32, f32, f32)>> {
+    let points: Vec<(f32, f32, f32)> = self
+      .x_edge
+      .iter()
+      .enumerate()
+      .map(|(i, &x)| {
+        (
+          x,
+          self.y_value.get(i).cloned().unwrap_or(0.0),
+          self.lower(i),
+        )
+      })
+      .collect();
+    let mask: Vec<bool> = points
+      .iter()
+      .map(|&(_, u, l)| self.is_filled(u, l))
+      .collect();
+
+    let mut regions = Vec::new();
+    let mut current = Vec::new();
+    for i in 0..points.len() {
+      if mask[i] {
+        current.push(points[i]);
+      }
+      if i + 1 < points.len() && mask[i] != mask[i + 1] {
+        // 两条边界在区间内交叉时，在交点处截断
+        let (x0, u0, l0) = points[i];
+        let (x1, u1, l1) = points[i + 1];
+        let (d0, d1) = (u0 - l0, u1 - l1);
+        let crossing = (d0 * d1 < 0.0).then(|| {
+          let t = d0 / (d0 - d1);
+          let y = u0 + t * (u1 - u0);
+          (x0 + t * (x1 - x0), y, y)
+        });
+        if mask[i] {
+          current.extend(crossing);
+          regions.push(std::mem::take(&mut current));
+        } else {
+          current.extend(crossing);
+        }
+      }
+    }
+    if !current.is_empty() {
+      regions.push(current);
+    }
+    regions
+  }
 }
 
 impl Drawable for Area {
-  fn draw(&self, pixmap: &mut tiny_skia::Pixmap, ts: &tiny_skia::Transform) {
+  fn draw(&self, pixmap: &mut tiny_skia::Pixmap, ts: &tiny_skia::Transform, mask: Option<&Mask>) {
     if self.x_edge.is_empty() {
       return;
     }
 
     let mut pb = PathBuilder::new();
-    let baseline = 0.0;
+    let map = |x: f32, y: f32| {
+      let mut p = Point::from_xy(x, y);
+      ts.map_point(&mut p);
+      p
+    };
 
     match self.area_type {
       // --- 模式 1: Step (阶梯状/柱状面积) ---
@@ -145,17 +244,16 @@ impl Drawable for Area {
           let x_l = edges[i];
           let x_r = edges[i + 1];
           let y_val = self.y_value.get(i).cloned().unwrap_or(0.0);
+          let low = self.lower(i);
+          if !self.is_filled(y_val, low) {
+            continue;
+          }
 
           // 构造矩形的四个点并映射
-          let mut p1 = Point::from_xy(x_l, baseline);
-          let mut p2 = Point::from_xy(x_l, y_val);
-          let mut p3 = Point::from_xy(x_r, y_val);
-          let mut p4 = Point::from_xy(x_r, baseline);
-
-          ts.map_point(&mut p1);
-          ts.map_point(&mut p2);
-          ts.map_point(&mut p3);
-          ts.map_point(&mut p4);
+          let p1 = map(x_l, low);
+          let p2 = map(x_l, y_val);
+          let p3 = map(x_r, y_val);
+          let p4 = map(x_r, low);
 
           pb.move_to(p1.x, p1.y);
           pb.line_to(p2.x, p2.y);
@@ -167,24 +265,18 @@ impl Drawable for Area {
 
       // --- 模式 2: Curve (折线面积填充) ---
       AreaType::Line => {
-        if !self.x_edge.is_empty() {
-          // 1. 起点：(x_0, baseline)
-          let mut p_start = Point::from_xy(self.x_edge[0], baseline);
-          ts.map_point(&mut p_start);
-          pb.move_to(p_start.x, p_start.y);
-
-          // 2. 连接所有数据点 (x_i, y_i)
-          for i in 0..self.x_edge.len() {
-            let y_val = self.y_value.get(i).cloned().unwrap_or(0.0);
-            let mut p = Point::from_xy(self.x_edge[i], y_val);
-            ts.map_point(&mut p);
+        // 每个区域：上边界正向连接，下边界反向连接
+        for region in self.line_regions() {
+          let p = map(region[0].0, region[0].1);
+          pb.move_to(p.x, p.y);
+          for &(x, upper, _) in &region[1..] {
+            let p = map(x, upper);
+            pb.line_to(p.x, p.y);
+          }
+          for &(x, _, lower) in region.iter().rev() {
+            let p = map(x, lower);
             pb.line_to(p.x, p.y);
           }
-
-          // 3. 终点：(x_last, baseline)
-          let mut p_end = Point::from_xy(*self.x_edge.last().unwrap(), baseline);
-          ts.map_point(&mut p_end);
-          pb.line_to(p_end.x, p_end.y);
           pb.close();
         }
       }
@@ -192,26 +284,65 @@ impl Drawable for Area {
 
     // --- 统一渲染逻辑 ---
     if let Some(path) = pb.finish() {
-      let mut paint = Paint::default();
-      let [r, g, b, a] = self.config.color;
+      let mut paint = self.config.paint();
+      let [r, g, b, a] = self.config.rgba();
 
       // 1. 填充路径 (使用半透明色)
       paint.set_color_rgba8(r, g, b, a / 2);
-      paint.anti_alias = true;
       pixmap.fill_path(
         &path,
         &paint,
         FillRule::Winding,
         Transform::identity(),
-        None,
+        mask,
       );
 
       // 2. 描边 (使用相同的颜色，或者你可以根据需求调深一点)
-      let stroke = Stroke {
-        width: self.config.stroke_width,
-        ..Default::default()
-      };
-      pixmap.stroke_path(&path, &paint, &stroke, Transform::identity(), None);
+      let stroke = self.config.stroke();
+      pixmap.stroke_path(&path, &paint, &stroke, Transform::identity(), mask);
+    }
+  }
+  fn draw_polar(&self, pixmap: &mut tiny_skia::Pixmap, polar: &Polar, mask: Option<&Mask>) {
+    if self.x_edge.is_empty() {
+      return;
+    }
+    let mut pb = PathBuilder::new();
+    match self.area_type {
+      // each step becomes a wedge
+      AreaType::Step => {
+        for (i, w) in self.x_edge.windows(2).enumerate() {
+          let y_val = self.y_value.get(i).cloned().unwrap_or(0.0);
+          let low = self.lower(i);
+          if self.is_filled(y_val, low) {
+            polar.push_wedge(&mut pb, (w[0], w[1]), (low, y_val));
+          }
+        }
+      }
+      AreaType::Line => {
+        for region in self.line_regions() {
+          let points: Vec<(f32, f32)> = region
+            .iter()
+            .map(|&(x, upper, _)| (x, upper))
+            .chain(region.iter().rev().map(|&(x, _, lower)| (x, lower)))
+            .collect();
+          polar.push_polyline(&mut pb, &points);
+          pb.close();
+        }
+      }
+    }
+    if let Some(path) = pb.finish() {
+      let mut paint = self.config.paint();
+      let [r, g, b, a] = self.config.rgba();
+      paint.set_color_rgba8(r, g, b, a / 2);
+      pixmap.fill_path(
+        &path,
+        &paint,
+        FillRule::Winding,
+        Transform::identity(),
+        mask,
+      );
+      let stroke = self.config.stroke();
+      pixmap.stroke_path(&path, &paint, &stroke, Transform::identity(), mask);
     }
   }
   fn bound(&self) -> Option<Bound> {
@@ -220,10 +351,16 @@ impl Drawable for Area {
     }
     let x_min = self.x_edge.iter().fold(f32::INFINITY, |a, &b| a.min(b));
     let x_max = self.x_edge.iter().fold(f32::NEG_INFINITY, |a, &b| a.max(b));
-    let y_min = self.y_value.iter().fold(f32::INFINITY, |a, &b| a.min(b));
+    let lower: Vec<f32> = (0..self.y_value.len()).map(|i| self.lower(i)).collect();
+    let y_min = self
+      .y_value
+      .iter()
+      .chain(lower.iter())
+      .fold(f32::INFINITY, |a, &b| a.min(b));
     let y_max = self
       .y_value
       .iter()
+      .chain(lower.iter())
       .fold(f32::NEG_INFINITY, |a, &b| a.max(b));
 
     Some(Bound {
@@ -243,3 +380,22 @@ impl Drawable for Area {
     self.config.color = color;
   }
 }
+
+#[test]
+fn test_area_crossing() {
+  let mut area = Area::new("a".to_string(), Config::default());
+  area.set_data(&[0.0, 1.0, 2.0], &[1.0, 3.0, 3.0]);
+  area.set_lower(&[2.0, 0.0, 4.0]);
+  area.set_where(|upper, lower| upper > lower);
+
+  // the fill starts and stops where both boundaries cross between the samples
+  assert_eq!(
+    area.line_regions(),
+    [vec![(0.25, 1.5, 1.5), (1.0, 3.0, 0.0), (1.75, 3.0, 3.0)]]
+  );
+  // the lower boundary is part of the bound
+  let b = area.bound().unwrap();
+  assert_eq!((b.x_min, b.x_max, b.y_min, b.y_max), (0.0, 2.0, 0.0, 4.0));
+  area.set_baseline(-2.0);
+  assert_eq!(area.bound().unwrap().y_min, -2.0);
+}
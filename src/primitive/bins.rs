@@ -0,0 +1,132 @@
+use crate::utils;
+
+/// How the bin edges of a histogram are computed from raw samples
+#[derive(Debug, Clone)]
+pub enum Bins {
+  /// fixed number of equal-width bins between min and max
+  Count(usize),
+  /// fixed bin width, edges aligned to multiples of the width
+  ///
+  /// a width giving more than `Bins::MAX_COUNT` bins is replaced by that many equal-width bins
+  Width(f32),
+  /// `ceil(log2(n)) + 1` bins
+  Sturges,
+  /// bin width `3.49 * σ * n^(-1/3)`
+  Scott,
+  /// bin width `2 * IQR * n^(-1/3)`
+  FreedmanDiaconis,
+  /// explicit bin edges, sorted before use with the non-finite and repeated ones dropped
+  Edges(Vec<f32>),
+}
+impl Default for Bins {
+  fn default() -> Self {
+    Bins::Count(10)
+  }
+}
+
+impl Bins {
+  /// most bins computed from the samples
+  pub const MAX_COUNT: usize = 10_000;
+
+  /// compute the bin edges for `sorted` samples (ascending, all finite)
+  pub(crate) fn edges(&self, sorted: &[f32]) -> Vec<f32> {
+    if let Bins::Edges(edges) = self {
+      let mut edges: Vec<f32> = edges.iter().cloned().filter(|e| e.is_finite()).collect();
+      edges.sort_by(|a, b| a.total_cmp(b));
+      edges.dedup();
+      return edges;
+    }
+    if sorted.is_empty() {
+      return Vec::new();
+    }
+    let (min, mut max) = (sorted[0], sorted[sorted.len() - 1]);
+    if max <= min {
+      max = min + 1.0;
+    }
+    let n = sorted.len() as f32;
+    let range = max - min;
+
+    // a width of 0 (no spread) falls back to sturges
+    let sturges = (n.log2().ceil() as usize + 1).max(1);
+    let count_from_width = |w: f32| {
+      if w > 0.0 {
+        (range / w).ceil().clamp(1.0, Self::MAX_COUNT as f32) as usize
+      } else {
+        sturges
+      }
+    };
+    let count = match self {
+      Bins::Count(count) => (*count).clamp(1, Self::MAX_COUNT),
+      Bins::Width(w) => {
+        if !(*w > 0.0 && w.is_finite()) {
+          return utils::linspace(min, max, sturges + 1);
+        }
+        let start = (min / w).floor() * w;
+        let end = (max / w).ceil() * w;
+        let count = ((end - start) / w).round().max(1.0);
+        if count > Self::MAX_COUNT as f32 {
+          return utils::linspace(min, max, Self::MAX_COUNT + 1);
+        }
+        return (0..=count as usize).map(|i| start + i as f32 * w).collect();
+      }
+      Bins::Sturges => sturges,
+      Bins::Scott => {
+        let mean = sorted.iter().sum::<f32>() / n;
+        let var = sorted.iter().map(|v| (v - mean).powi(2)).sum::<f32>() / n;
+        count_from_width(3.49 * var.sqrt() * n.powf(-1.0 / 3.0))
+      }
+      Bins::FreedmanDiaconis => {
+        let iqr = utils::quantile(sorted, 0.75) - utils::quantile(sorted, 0.25);
+        count_from_width(2.0 * iqr * n.powf(-1.0 / 3.0))
+      }
+      Bins::Edges(_) => unreachable!(),
+    };
+    utils::linspace(min, max, count + 1)
+  }
+}
+
+/// weighted count of `samples` in each bin of `edges`, the last bin includes its right edge
+pub(crate) fn count(edges: &[f32], samples: &[f32], weights: Option<&[f32]>) -> Vec<f32> {
+  let num_bins = edges.len().saturating_sub(1);
+  let mut counts = vec![0.0; num_bins];
+  if num_bins == 0 {
+    return counts;
+  }
+  let last = edges[num_bins];
+  for (i, &v) in samples.iter().enumerate() {
+    if !v.is_finite() || v < edges[0] || v > last {
+      continue;
+    }
+    let w = weights.and_then(|w| w.get(i)).cloned().unwrap_or(1.0);
+    let bin = (edges.partition_point(|&e| e <= v).saturating_sub(1)).min(num_bins - 1);
+    counts[bin] += w;
+  }
+  counts
+}
+
+#[test]
+fn test_bins() {
+  let sorted = [0., 1., 2., 3., 4., 5., 6., 7., 8., 10.];
+  let edges = Bins::Count(5).edges(&sorted);
+  assert_eq!(edges, vec![0., 2., 4., 6., 8., 10.]);
+  assert_eq!(count(&edges, &sorted, None), vec![2., 2., 2., 2., 2.]);
+
+  let edges = Bins::Width(4.).edges(&sorted);
+  assert_eq!(edges, vec![0., 4., 8., 12.]);
+  assert_eq!(Bins::Sturges.edges(&sorted).len(), 6);
+}
+
+#[test]
+fn test_invalid_bins() {
+  let sorted = [0., 1., 2., 3., 4., 5., 6., 7., 8., 10.];
+  // unsorted, repeated and NaN edges are cleaned up before counting
+  let edges = Bins::Edges(vec![10., 0., f32::NAN, 5., 5.]).edges(&sorted);
+  assert_eq!(edges, vec![0., 5., 10.]);
+  assert_eq!(count(&edges, &sorted, None), vec![5., 5.]);
+
+  // a tiny width is capped instead of allocating billions of edges
+  let edges = Bins::Width(1e-9).edges(&sorted);
+  assert_eq!(edges.len(), Bins::MAX_COUNT + 1);
+  assert_eq!((edges[0], edges[Bins::MAX_COUNT]), (0., 10.));
+  assert_eq!(Bins::Width(f32::NAN).edges(&sorted).len(), 6);
+}
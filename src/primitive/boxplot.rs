@@ -0,0 +1,392 @@
+use tiny_skia::{FillRule, Mask, Paint, PathBuilder, Pixmap, Point, Transform};
+
+use crate::{
+  color,
+  drawable::{Bound, Drawable},
+  primitive::{Config, Orientation},
+  utils,
+};
+
+/// How far the whiskers reach
+#[derive(Debug, Clone, Copy)]
+pub enum Whisker {
+  /// furthest sample within `k * IQR` of the box, Tukey uses `k = 1.5`
+  Tukey(f32),
+  /// the given percentiles, both in `[0, 100]`
+  Percentile(f32, f32),
+}
+impl Default for Whisker {
+  fn default() -> Self {
+    Whisker::Tukey(1.5)
+  }
+}
+
+#[derive(Debug, PartialEq)]
+struct BoxStats {
+  q1: f32,
+  median: f32,
+  q3: f32,
+  mean: f32,
+  low: f32,
+  high: f32,
+  /// half height of the notch around the median
+  notch: f32,
+  outliers: Vec<f32>,
+}
+impl BoxStats {
+  fn new(sorted: &[f32], whisker: Whisker) -> Option<Self> {
+    if sorted.is_empty() {
+      return None;
+    }
+    let n = sorted.len() as f32;
+    let q1 = utils::quantile(sorted, 0.25);
+    let median = utils::quantile(sorted, 0.5);
+    let q3 = utils::quantile(sorted, 0.75);
+    let iqr = q3 - q1;
+    let mean = sorted.iter().sum::<f32>() / n;
+
+    let (low, high) = match whisker {
+      Whisker::Tukey(k) => {
+        let lo_fence = q1 - k * iqr;
+        let hi_fence = q3 + k * iqr;
+        // the whisker ends on a real sample, never inside the box
+        let low = sorted
+          .iter()
+          .cloned()
+          .find(|&v| v >= lo_fence)
+          .unwrap_or(q1);
+        let high = sorted
+          .iter()
+          .rev()
+          .cloned()
+          .find(|&v| v <= hi_fence)
+          .unwrap_or(q3);
+        (low.min(q1), high.max(q3))
+      }
+      Whisker::Percentile(lo, hi) => (
+        utils::quantile(sorted, lo / 100.0),
+        utils::quantile(sorted, hi / 100.0),
+      ),
+    };
+    let outliers = sorted
+      .iter()
+      .cloned()
+      .filter(|&v| v < low || v > high)
+      .collect();
+
+    Some(Self {
+      q1,
+      median,
+      q3,
+      mean,
+      low,
+      high,
+      notch: 1.57 * iqr / n.sqrt(),
+      outliers,
+    })
+  }
+}
+
+struct Group {
+  label: String,
+  /// sorted samples without NaN
+  samples: Vec<f32>,
+  config: Config,
+}
+
+/// Box-and-whisker plot, one box per group of raw samples
+/// * group `i` is centered at `i + 1` on the category axis
+pub struct BoxPlot {
+  name: String,
+  groups: Vec<Group>,
+
+  whisker: Whisker,
+  orientation: Orientation,
+  box_width: f32,
+  notch: bool,
+  show_mean: bool,
+
+  color_index: usize,
+}
+
+impl BoxPlot {
+  pub fn new(name: String) -> Self {
+    Self {
+      name,
+      groups: Vec::new(),
+      whisker: Whisker::default(),
+      orientation: Orientation::Vertical,
+      box_width: 0.5,
+      notch: false,
+      show_mean: false,
+      color_index: 0,
+    }
+  }
+  fn gen_config(&mut self) -> Config {
+    let index = self.color_index;
+    let color = color::get_color(index);
+    self.color_index = (index + 1) & 7;
+
+    Config {
+      color,
+      ..Config::default()
+    }
+  }
+  /// Adds a group of raw samples, drawn as one box.
+  ///
+  /// # Arguments
+  ///
+  /// * `label` - The label shown on the category axis.
+  /// * `samples` - The raw samples, `NaN` values are ignored.
+  pub fn add_group(&mut self, label: String, samples: &[f32]) {
+    let config = self.gen_config();
+    let mut samples: Vec<f32> = samples.iter().cloned().filter(|v| !v.is_nan()).collect();
+    samples.sort_by(|a, b| a.total_cmp(b));
+    self.groups.push(Group {
+      label,
+      samples,
+      config,
+    });
+  }
+  /// Sets how far the whiskers reach, default is `Whisker::Tukey(1.5)`.
+  /// Samples beyond the whiskers are drawn as outliers.
+  pub fn set_whisker(&mut self, whisker: Whisker) {
+    self.whisker = whisker;
+  }
+  /// Sets the orientation of the boxes.
+  pub fn set_orientation(&mut self, orientation: Orientation) {
+    self.orientation = orientation;
+  }
+  /// Sets the box width relative to the category slot, in `(0, 1]`.
+  pub fn set_box_width(&mut self, width: f32) {
+    self.box_width = width.clamp(0.05, 1.0);
+  }
+  /// Shows a notch around the median (95% confidence interval).
+  pub fn set_notch(&mut self, notch: bool) {
+    self.notch = notch;
+  }
+  /// Shows a marker at the mean of each group.
+  pub fn set_show_mean(&mut self, show_mean: bool) {
+    self.show_mean = show_mean;
+  }
+}
+
+// implement for draw
+impl BoxPlot {
+  fn draw_group(
+    &self, pixmap: &mut Pixmap, ts: &Transform, pos: f32, group: &Group, mask: Option<&Mask>,
+  ) {
+    let Some(stats) = BoxStats::new(&group.samples, self.whisker) else {
+      return;
+    };
+    let half = self.box_width * 0.5;
+    let map = |p: f32, v: f32| {
+      let mut point = self.orientation.point(p, v);
+      ts.map_point(&mut point);
+      point
+    };
+    let paint = group.config.paint();
+    let stroke = group.config.stroke();
+
+    // box (notched if requested)
+    let mut pb = PathBuilder::new();
+    let (l, r) = (pos - half, pos + half);
+    let outline: Vec<Point> = if self.notch {
+      let (n_lo, n_hi) = (stats.median - stats.notch, stats.median + stats.notch);
+      let inner = half * 0.5;
+      vec![
+        map(l, stats.q1),
+        map(l, n_lo),
+        map(pos - inner, stats.median),
+        map(l, n_hi),
+        map(l, stats.q3),
+        map(r, stats.q3),
+        map(r, n_hi),
+        map(pos + inner, stats.median),
+        map(r, n_lo),
+        map(r, stats.q1),
+      ]
+    } else {
+      vec![
+        map(l, stats.q1),
+        map(l, stats.q3),
+        map(r, stats.q3),
+        map(r, stats.q1),
+      ]
+    };
+    pb.move_to(outline[0].x, outline[0].y);
+    for p in &outline[1..] {
+      pb.line_to(p.x, p.y);
+    }
+    pb.close();
+    if let Some(path) = pb.finish() {
+      let mut fill = paint.clone();
+      let [r, g, b, a] = group.config.rgba();
+      fill.set_color_rgba8(r, g, b, a / 2);
+      pixmap.fill_path(&path, &fill, FillRule::Winding, Transform::identity(), mask);
+      pixmap.stroke_path(&path, &paint, &stroke, Transform::identity(), mask);
+    }
+
+    // whiskers with caps
+    let cap = half * 0.5;
+    let mut pb = PathBuilder::new();
+    for (from, to) in [(stats.q1, stats.low), (stats.q3, stats.high)] {
+      let (p1, p2) = (map(pos, from), map(pos, to));
+      pb.move_to(p1.x, p1.y);
+      pb.line_to(p2.x, p2.y);
+      let (c1, c2) = (map(pos - cap, to), map(pos + cap, to));
+      pb.move_to(c1.x, c1.y);
+      pb.line_to(c2.x, c2.y);
+    }
+    if let Some(path) = pb.finish() {
+      pixmap.stroke_path(&path, &paint, &stroke, Transform::identity(), mask);
+    }
+
+    // median
+    let inset = if self.notch { half * 0.5 } else { half };
+    let (m1, m2) = (
+      map(pos - inset, stats.median),
+      map(pos + inset, stats.median),
+    );
+    let mut pb = PathBuilder::new();
+    pb.move_to(m1.x, m1.y);
+    pb.line_to(m2.x, m2.y);
+    if let Some(path) = pb.finish() {
+      let mut median_stroke = stroke.clone();
+      median_stroke.width += 1.0;
+      median_stroke.dash = None;
+      pixmap.stroke_path(&path, &paint, &median_stroke, Transform::identity(), mask);
+    }
+
+    // outliers as hollow circles
+    let mut pb = PathBuilder::new();
+    for &v in &stats.outliers {
+      let p = map(pos, v);
+      pb.push_circle(p.x, p.y, 3.0);
+    }
+    if let Some(path) = pb.finish() {
+      let mut outlier_stroke = stroke.clone();
+      outlier_stroke.width = 1.0;
+      outlier_stroke.dash = None;
+      pixmap.stroke_path(&path, &paint, &outlier_stroke, Transform::identity(), mask);
+    }
+
+    // mean as a diamond
+    if self.show_mean {
+      let c = map(pos, stats.mean);
+      let size = 4.0;
+      let mut pb = PathBuilder::new();
+      pb.move_to(c.x, c.y - size);
+      pb.line_to(c.x + size, c.y);
+      pb.line_to(c.x, c.y + size);
+      pb.line_to(c.x - size, c.y);
+      pb.close();
+      if let Some(path) = pb.finish() {
+        let [r, g, b, a] = color::get_fg();
+        let mut mean_paint = Paint::default();
+        mean_paint.set_color_rgba8(r, g, b, a);
+        mean_paint.anti_alias = true;
+        pixmap.fill_path(
+          &path,
+          &mean_paint,
+          FillRule::Winding,
+          Transform::identity(),
+          mask,
+        );
+      }
+    }
+  }
+  fn labels(&self) -> Vec<(f32, String)> {
+    self
+      .groups
+      .iter()
+      .enumerate()
+      .map(|(i, g)| ((i + 1) as f32, g.label.clone()))
+      .collect()
+  }
+}
+
+impl Drawable for BoxPlot {
+  fn draw(&self, pixmap: &mut Pixmap, ts: &Transform, mask: Option<&Mask>) {
+    for (index, group) in self.groups.iter().enumerate() {
+      if group.config.is_hidden {
+        continue;
+      }
+      self.draw_group(pixmap, ts, (index + 1) as f32, group, mask);
+    }
+  }
+  fn bound(&self) -> Option<Bound> {
+    let mut v_min = f32::INFINITY;
+    let mut v_max = f32::NEG_INFINITY;
+    for group in self.groups.iter().filter(|g| !g.config.is_hidden) {
+      if let Some(stats) = BoxStats::new(&group.samples, self.whisker) {
+        let lo = stats
+          .outliers
+          .first()
+          .map_or(stats.low, |&v| v.min(stats.low));
+        let hi = stats
+          .outliers
+          .last()
+          .map_or(stats.high, |&v| v.max(stats.high));
+        v_min = v_min.min(lo);
+        v_max = v_max.max(hi);
+      }
+    }
+    if v_min > v_max {
+      return None;
+    }
+
+    let p_min = 0.5;
+    let p_max = self.groups.len() as f32 + 0.5;
+    Some(match self.orientation {
+      Orientation::Vertical => Bound {
+        x_min: p_min,
+        x_max: p_max,
+        y_min: v_min,
+        y_max: v_max,
+      },
+      Orientation::Horizontal => Bound {
+        x_min: v_min,
+        x_max: v_max,
+        y_min: p_min,
+        y_max: p_max,
+      },
+    })
+  }
+  fn name(&self) -> String {
+    self.name.clone()
+  }
+  // not used, manage color in self
+  fn get_color(&self) -> [u8; 4] {
+    [255, 255, 255, 255]
+  }
+  fn set_color(&mut self, _color: [u8; 4]) {}
+  fn x_labels(&self) -> Option<Vec<(f32, String)>> {
+    match self.orientation {
+      Orientation::Vertical => Some(self.labels()),
+      Orientation::Horizontal => None,
+    }
+  }
+  fn y_labels(&self) -> Option<Vec<(f32, String)>> {
+    match self.orientation {
+      Orientation::Vertical => None,
+      Orientation::Horizontal => Some(self.labels()),
+    }
+  }
+}
+
+#[test]
+fn test_box_stats() {
+  let sorted = [1., 2., 3., 4., 5., 6., 7., 8., 9., 30.];
+  let stats = BoxStats::new(&sorted, Whisker::Tukey(1.5)).unwrap();
+  assert_eq!(stats.median, 5.5);
+  assert_eq!(stats.q1, 3.25);
+  assert_eq!(stats.q3, 7.75);
+  assert_eq!(stats.low, 1.);
+  assert_eq!(stats.high, 9.);
+  assert_eq!(stats.outliers, vec![30.]);
+
+  let stats = BoxStats::new(&sorted, Whisker::Percentile(0., 100.)).unwrap();
+  assert_eq!(stats.high, 30.);
+  assert!(stats.outliers.is_empty());
+}
@@ -0,0 +1,293 @@
+use tiny_skia::{Mask, PathBuilder, Pixmap, Point, Rect, Transform};
+
+use crate::{
+  color,
+  drawable::{Bound, Drawable},
+  primitive::Config,
+};
+
+/// How each period is drawn
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum CandleStyle {
+  /// a body from open to close with wicks to high and low
+  #[default]
+  Candle,
+  /// a vertical high-low bar with ticks for open (left) and close (right)
+  Ohlc,
+}
+
+/// Open/high/low/close chart, one candle per period
+/// * `x` is the time of each period in any unit (days, hours, ...), gaps are kept
+/// * the body width is relative to the smallest distance between two periods
+pub struct Candlestick {
+  name: String,
+  x: Vec<f32>,
+  open: Vec<f32>,
+  high: Vec<f32>,
+  low: Vec<f32>,
+  close: Vec<f32>,
+  volume: Option<Vec<f32>>,
+  labels: Vec<String>,
+
+  style: CandleStyle,
+  body_width: f32,
+  hollow: bool,
+  up_color: [u8; 4],
+  down_color: [u8; 4],
+
+  config: Config,
+}
+
+impl Candlestick {
+  /// share of the price range the volume bars take below the candles
+  const VOLUME_RATIO: f32 = 0.25;
+
+  pub fn new(name: String, config: Config) -> Self {
+    Self {
+      name,
+      x: Vec::new(),
+      open: Vec::new(),
+      high: Vec::new(),
+      low: Vec::new(),
+      close: Vec::new(),
+      volume: None,
+      labels: Vec::new(),
+      style: CandleStyle::default(),
+      body_width: 0.7,
+      hollow: false,
+      up_color: color::get_color(2),
+      down_color: color::get_color(0),
+      config,
+    }
+  }
+  /// Sets the prices of each period.
+  ///
+  /// # Arguments
+  ///
+  /// * `x` - The ascending time of each period.
+  /// * `open`, `high`, `low`, `close` - The prices of each period, as long as `x`.
+  pub fn set_data(&mut self, x: &[f32], open: &[f32], high: &[f32], low: &[f32], close: &[f32]) {
+    let n = x
+      .len()
+      .min(open.len())
+      .min(high.len())
+      .min(low.len())
+      .min(close.len());
+    self.x = x[..n].to_vec();
+    self.open = open[..n].to_vec();
+    self.high = high[..n].to_vec();
+    self.low = low[..n].to_vec();
+    self.close = close[..n].to_vec();
+  }
+  /// Sets the traded volume of each period, drawn as bars below the candles.
+  pub fn set_volume(&mut self, volume: &[f32]) {
+    self.volume = Some(volume.to_vec());
+  }
+  /// Sets the labels of the periods (e.g. dates) shown on the x axis instead of the time.
+  ///
+  /// At most about ten labels are shown, evenly picked from the periods.
+  pub fn set_labels(&mut self, labels: &[&str]) {
+    self.labels = labels.iter().map(|l| l.to_string()).collect();
+  }
+  /// Sets the style of the periods, default is `CandleStyle::Candle`.
+  pub fn set_style(&mut self, style: CandleStyle) {
+    self.style = style;
+  }
+  /// Sets the body width relative to the period, default is `0.7`.
+  pub fn set_body_width(&mut self, ratio: f32) {
+    self.body_width = ratio.clamp(0.05, 1.0);
+  }
+  /// Draws the bodies of rising periods as outlines only.
+  pub fn set_hollow(&mut self, hollow: bool) {
+    self.hollow = hollow;
+  }
+  /// Sets the colors of rising (`close >= open`) and falling periods.
+  pub fn set_colors(&mut self, up: [u8; 4], down: [u8; 4]) {
+    self.up_color = up;
+    self.down_color = down;
+  }
+
+  /// smallest distance between two periods
+  fn period(&self) -> f32 {
+    let p = self
+      .x
+      .windows(2)
+      .map(|w| w[1] - w[0])
+      .filter(|d| *d > 0.0)
+      .fold(f32::INFINITY, f32::min);
+    if p.is_finite() { p } else { 1.0 }
+  }
+  fn price_range(&self) -> (f32, f32) {
+    let lo = self.low.iter().cloned().fold(f32::INFINITY, f32::min);
+    let hi = self.high.iter().cloned().fold(f32::NEG_INFINITY, f32::max);
+    (lo, hi)
+  }
+  /// the data band `(bottom, height per unit of volume)` of the volume bars
+  fn volume_band(&self) -> Option<(f32, f32)> {
+    let volume = self.volume.as_ref()?;
+    let max = volume.iter().cloned().fold(0.0, f32::max);
+    if max <= 0.0 {
+      return None;
+    }
+    let (lo, hi) = self.price_range();
+    let band = ((hi - lo) * Self::VOLUME_RATIO).max(1e-6);
+    Some((lo - band, band / max))
+  }
+  /// whether period `i` rises, a period closing at its open counts as rising
+  fn is_up(&self, i: usize) -> bool {
+    self.close[i] >= self.open[i]
+  }
+  fn config_of(&self, up: bool) -> Config {
+    let mut config = self.config;
+    config.color = if up { self.up_color } else { self.down_color };
+    config
+  }
+}
+
+impl Drawable for Candlestick {
+  fn draw(&self, pixmap: &mut Pixmap, ts: &Transform, mask: Option<&Mask>) {
+    if self.config.is_hidden || self.x.is_empty() {
+      return;
+    }
+    let map = |x: f32, y: f32| {
+      let mut p = Point::from_xy(x, y);
+      ts.map_point(&mut p);
+      p
+    };
+    let rect = |l: f32, r: f32, bottom: f32, top: f32| {
+      let (p1, p2) = (map(l, top), map(r, bottom));
+      // keep flat bodies (open == close) visible
+      Rect::from_ltrb(p1.x, p1.y, p2.x, p2.y.max(p1.y + 1.0))
+    };
+    let half = self.period() * self.body_width * 0.5;
+
+    if let Some((bottom, unit)) = self.volume_band() {
+      let volume = self.volume.as_deref().unwrap_or_default();
+      for (i, &v) in volume.iter().enumerate().take(self.x.len()) {
+        let config = self.config_of(self.is_up(i));
+        let mut fill = config.paint();
+        let [r, g, b, a] = config.rgba();
+        fill.set_color_rgba8(r, g, b, a / 2);
+        if let Some(r) = rect(
+          self.x[i] - half,
+          self.x[i] + half,
+          bottom,
+          bottom + v * unit,
+        ) {
+          pixmap.fill_rect(r, &fill, Transform::identity(), mask);
+        }
+      }
+    }
+
+    for i in 0..self.x.len() {
+      let (x, open, close) = (self.x[i], self.open[i], self.close[i]);
+      let up = self.is_up(i);
+      let config = self.config_of(up);
+      let paint = config.paint();
+      let stroke = config.stroke();
+      let (high, low) = (map(x, self.high[i]), map(x, self.low[i]));
+
+      let mut pb = PathBuilder::new();
+      match self.style {
+        CandleStyle::Candle => {
+          let (body_top, body_bottom) = (open.max(close), open.min(close));
+          // wicks stop at the body, so hollow bodies stay empty
+          let (top, bottom) = (map(x, body_top), map(x, body_bottom));
+          pb.move_to(high.x, high.y);
+          pb.line_to(top.x, top.y);
+          pb.move_to(bottom.x, bottom.y);
+          pb.line_to(low.x, low.y);
+          if let Some(body) = rect(x - half, x + half, body_bottom, body_top) {
+            if self.hollow && up {
+              pb.push_rect(body);
+            } else {
+              pixmap.fill_rect(body, &paint, Transform::identity(), mask);
+            }
+          }
+        }
+        CandleStyle::Ohlc => {
+          let (o, c) = (map(x, open), map(x, close));
+          let (l, r) = (map(x - half, open), map(x + half, close));
+          pb.move_to(high.x, high.y);
+          pb.line_to(low.x, low.y);
+          pb.move_to(l.x, o.y);
+          pb.line_to(o.x, o.y);
+          pb.move_to(c.x, c.y);
+          pb.line_to(r.x, c.y);
+        }
+      }
+      if let Some(path) = pb.finish() {
+        pixmap.stroke_path(&path, &paint, &stroke, Transform::identity(), mask);
+      }
+    }
+  }
+  fn bound(&self) -> Option<Bound> {
+    if self.x.is_empty() {
+      return None;
+    }
+    let half = self.period() * 0.5;
+    let (mut y_min, y_max) = self.price_range();
+    if let Some((bottom, _)) = self.volume_band() {
+      y_min = bottom;
+    }
+    Some(Bound {
+      x_min: self.x[0] - half,
+      x_max: self.x[self.x.len() - 1] + half,
+      y_min,
+      y_max,
+    })
+  }
+  fn name(&self) -> String {
+    self.name.clone()
+  }
+  // not used, colored by up/down
+  fn get_color(&self) -> [u8; 4] {
+    [255, 255, 255, 255]
+  }
+  fn set_color(&mut self, _color: [u8; 4]) {}
+  fn x_labels(&self) -> Option<Vec<(f32, String)>> {
+    if self.labels.is_empty() {
+      return None;
+    }
+    let step = self.x.len().div_ceil(10).max(1);
+    Some(
+      self
+        .x
+        .iter()
+        .zip(self.labels.iter())
+        .step_by(step)
+        .map(|(&x, l)| (x, l.clone()))
+        .collect(),
+    )
+  }
+}
+
+#[test]
+fn test_candlestick() {
+  let mut candles = Candlestick::new("c".to_string(), Config::default());
+  candles.set_data(
+    &[0.0, 2.0, 3.0],
+    &[10.0, 12.0, 11.0],
+    &[13.0, 14.0, 12.0],
+    &[9.0, 10.0, 8.0],
+    &[12.0, 11.0, 11.0],
+  );
+  // half of the smallest period on both sides, from the lowest low to the highest high
+  let b = candles.bound().unwrap();
+  assert_eq!((b.x_min, b.x_max, b.y_min, b.y_max), (-0.5, 3.5, 8.0, 14.0));
+
+  // the volume takes a quarter of the price range below the candles
+  candles.set_volume(&[100.0, 200.0, 0.0]);
+  assert_eq!(candles.volume_band(), Some((6.5, 1.5 / 200.0)));
+  assert_eq!(candles.bound().unwrap().y_min, 6.5);
+  candles.set_volume(&[0.0; 3]);
+  assert_eq!(candles.bound().unwrap().y_min, 8.0);
+
+  // a period closing at its open counts as rising
+  let (up, down) = ([0, 255, 0, 255], [255, 0, 0, 255]);
+  candles.set_colors(up, down);
+  let colors: Vec<_> = (0..3)
+    .map(|i| candles.config_of(candles.is_up(i)).color)
+    .collect();
+  assert_eq!(colors, [up, down, up]);
+}
@@ -1,8 +1,98 @@
+use tiny_skia::{LineCap, LineJoin, Paint, Point, Stroke, StrokeDash};
+
+/// Dash pattern used when stroking a line
+/// * the lengths are multiplied by the stroke width
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum LineStyle {
+  #[default]
+  Solid,
+  Dashed,
+  Dotted,
+  DashDot,
+  /// alternating on and off lengths, an odd pattern is repeated once as in SVG,
+  /// an empty or invalid pattern (negative, NaN or all zero) is drawn solid
+  Custom(DashPattern),
+}
+
+/// Up to `DashPattern::MAX` dash lengths stored inline, so `LineStyle` stays `Copy`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DashPattern {
+  lengths: [f32; DashPattern::MAX],
+  len: usize,
+}
+impl DashPattern {
+  pub const MAX: usize = 8;
+
+  /// Alternating on and off lengths, the ones past `MAX` are dropped.
+  pub fn new(lengths: &[f32]) -> Self {
+    let len = lengths.len().min(Self::MAX);
+    let mut pattern = Self {
+      lengths: [0.0; Self::MAX],
+      len,
+    };
+    pattern.lengths[..len].copy_from_slice(&lengths[..len]);
+    pattern
+  }
+  /// the stored lengths, before they are scaled by the stroke width
+  pub fn lengths(&self) -> &[f32] {
+    &self.lengths[..self.len]
+  }
+}
+
+impl LineStyle {
+  /// `LineStyle::Custom` with the given on and off lengths, see `DashPattern::new`
+  pub fn custom(lengths: &[f32]) -> Self {
+    LineStyle::Custom(DashPattern::new(lengths))
+  }
+  /// build the `StrokeDash` for the given stroke width, `None` means solid
+  pub(crate) fn dash(&self, width: f32) -> Option<StrokeDash> {
+    let width = width.max(1.0);
+    let array = match self {
+      LineStyle::Solid => return None,
+      LineStyle::Dashed => vec![4.0, 2.0],
+      LineStyle::Dotted => vec![1.0, 2.0],
+      LineStyle::DashDot => vec![4.0, 2.0, 1.0, 2.0],
+      LineStyle::Custom(pattern) if pattern.len % 2 == 1 => pattern.lengths().repeat(2),
+      LineStyle::Custom(pattern) => pattern.lengths().to_vec(),
+    };
+    if array.iter().any(|v| !v.is_finite()) {
+      return None;
+    }
+    StrokeDash::new(array.iter().map(|v| v * width).collect(), 0.0)
+  }
+}
+
+/// Direction in which categorical primitives grow from their slot
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum Orientation {
+  /// categories along x, values along y
+  #[default]
+  Vertical,
+  /// categories along y, values along x
+  Horizontal,
+}
+impl Orientation {
+  /// map a `(category position, value)` pair to a data point
+  pub(crate) fn point(&self, pos: f32, val: f32) -> Point {
+    match self {
+      Orientation::Vertical => Point::from_xy(pos, val),
+      Orientation::Horizontal => Point::from_xy(val, pos),
+    }
+  }
+}
+
 #[derive(Debug, Clone, Copy)]
 pub struct Config {
   pub is_hidden: bool,
   pub color: [u8; 4],
   pub stroke_width: f32,
+  pub line_style: LineStyle,
+  /// `None` keeps the default of the primitive: round for `Curve`, butt elsewhere
+  pub line_cap: Option<LineCap>,
+  /// `None` keeps the default of the primitive: round for `Curve`, miter elsewhere
+  pub line_join: Option<LineJoin>,
+  /// multiplied with the alpha channel of `color`, in `[0, 1]`
+  pub alpha: f32,
 }
 impl Default for Config {
   fn default() -> Self {
@@ -10,6 +100,102 @@ impl Default for Config {
       is_hidden: false,
       color: [0, 0, 0, 0],
       stroke_width: 2.0,
+      line_style: LineStyle::Solid,
+      line_cap: None,
+      line_join: None,
+      alpha: 1.0,
     }
   }
 }
+
+impl Config {
+  /// `color` with `alpha` applied
+  pub(crate) fn rgba(&self) -> [u8; 4] {
+    let [r, g, b, a] = self.color;
+    let a = (a as f32 * self.alpha.clamp(0.0, 1.0)).round() as u8;
+    [r, g, b, a]
+  }
+  /// anti-aliased paint filled with `rgba()`
+  pub(crate) fn paint(&self) -> Paint<'static> {
+    let [r, g, b, a] = self.rgba();
+    let mut paint = Paint::default();
+    paint.set_color_rgba8(r, g, b, a);
+    paint.anti_alias = true;
+    paint
+  }
+  /// stroke with the configured width, cap, join and dash
+  pub(crate) fn stroke(&self) -> Stroke {
+    self.stroke_or(LineCap::default(), LineJoin::default())
+  }
+  /// like `stroke`, with the given cap and join when they are not configured
+  pub(crate) fn stroke_or(&self, cap: LineCap, join: LineJoin) -> Stroke {
+    Stroke {
+      width: self.stroke_width,
+      line_cap: self.line_cap.unwrap_or(cap),
+      line_join: self.line_join.unwrap_or(join),
+      dash: self.line_style.dash(self.stroke_width),
+      ..Stroke::default()
+    }
+  }
+}
+
+#[test]
+fn test_dash() {
+  // number of segments a 100px line is dashed into
+  let len = |style: LineStyle, width: f32| {
+    let dash = style.dash(width)?;
+    let mut pb = tiny_skia::PathBuilder::new();
+    pb.move_to(0.0, 0.0);
+    pb.line_to(100.0, 0.0);
+    Some(pb.finish()?.dash(&dash, 1.0)?.segments().count())
+  };
+  assert!(LineStyle::Solid.dash(2.0).is_none());
+  // lengths scale with the width, thin lines use a width of 1
+  assert!(len(LineStyle::Dashed, 2.0) < len(LineStyle::Dashed, 0.5));
+  // an odd pattern is repeated, so it still dashes
+  assert!(len(LineStyle::custom(&[3.0]), 1.0).is_some());
+  assert_eq!(
+    len(LineStyle::custom(&[3.0]), 1.0),
+    len(LineStyle::custom(&[3.0, 3.0]), 1.0)
+  );
+  // invalid patterns fall back to solid
+  for array in [&[][..], &[0.0, 0.0], &[2.0, -1.0], &[f32::NAN, 1.0]] {
+    assert!(LineStyle::custom(array).dash(1.0).is_none());
+  }
+
+  // a pattern computed at runtime, cut to the inline capacity
+  let scaled: Vec<f32> = (1..=10).map(|i| i as f32 * 0.5).collect();
+  let pattern = DashPattern::new(&scaled);
+  assert_eq!(pattern.lengths(), &scaled[..DashPattern::MAX]);
+  assert!(LineStyle::Custom(pattern).dash(1.0).is_some());
+}
+
+#[test]
+fn test_stroke() {
+  let mut config = Config {
+    stroke_width: 3.0,
+    line_style: LineStyle::Dotted,
+    ..Config::default()
+  };
+  let stroke = config.stroke();
+  assert_eq!(stroke.width, 3.0);
+  assert!(stroke.dash.is_some());
+  assert_eq!(
+    (stroke.line_cap, stroke.line_join),
+    (LineCap::Butt, LineJoin::Miter)
+  );
+  let stroke = config.stroke_or(LineCap::Round, LineJoin::Round);
+  assert_eq!(
+    (stroke.line_cap, stroke.line_join),
+    (LineCap::Round, LineJoin::Round)
+  );
+
+  // a configured cap and join win over the primitive default
+  config.line_cap = Some(LineCap::Square);
+  config.line_join = Some(LineJoin::Bevel);
+  let stroke = config.stroke_or(LineCap::Round, LineJoin::Round);
+  assert_eq!(
+    (stroke.line_cap, stroke.line_join),
+    (LineCap::Square, LineJoin::Bevel)
+  );
+}
@@ -0,0 +1,458 @@
+use tiny_skia::{Color, FillRule, Mask, Paint, PathBuilder, Pixmap, Point, Rect, Transform};
+
+use crate::{
+  axis::Axis,
+  color::{self, Colormap},
+  drawable::{Bound, Drawable},
+  primitive::Config,
+  text_render::{TextRender, TextStyle},
+};
+
+/// a corner of a grid cell, `(x, y, z)`
+type Vertex = (f32, f32, f32);
+
+/// Scalar field sampled on a rectilinear grid, shared by `Contour` and `ContourFilled`
+#[derive(Default)]
+struct Field {
+  x: Vec<f32>,
+  y: Vec<f32>,
+  /// `z[j][i]` is the value at `(x[i], y[j])`
+  z: Vec<Vec<f32>>,
+  levels: Option<Vec<f32>>,
+  colormap: Colormap,
+}
+
+impl Field {
+  fn set_data(&mut self, x: &[f32], y: &[f32], z: &[Vec<f32>]) {
+    self.x = x.to_vec();
+    self.y = y.to_vec();
+    self.z = z
+      .iter()
+      .take(y.len())
+      .map(|row| row.iter().take(x.len()).cloned().collect())
+      .collect();
+  }
+  fn set_fn(&mut self, x: &[f32], y: &[f32], f: impl Fn(f32, f32) -> f32) {
+    self.x = x.to_vec();
+    self.y = y.to_vec();
+    self.z = y
+      .iter()
+      .map(|&yv| x.iter().map(|&xv| f(xv, yv)).collect())
+      .collect();
+  }
+  fn is_valid(&self) -> bool {
+    self.x.len() >= 2
+      && self.y.len() >= 2
+      && self.z.len() == self.y.len()
+      && self.z.iter().all(|row| row.len() == self.x.len())
+  }
+  fn z_range(&self) -> (f32, f32) {
+    self
+      .z
+      .iter()
+      .flatten()
+      .filter(|v| v.is_finite())
+      .fold((f32::INFINITY, f32::NEG_INFINITY), |(lo, hi), &v| {
+        (lo.min(v), hi.max(v))
+      })
+  }
+  /// user levels, or "nice" levels covering the whole z range
+  fn levels(&self) -> Vec<f32> {
+    if let Some(levels) = &self.levels {
+      return levels.clone();
+    }
+    let (lo, hi) = self.z_range();
+    if lo > hi {
+      return Vec::new();
+    }
+    if lo == hi {
+      return vec![lo];
+    }
+    let (interval, _) = Axis::calculate_tick_interval(hi - lo);
+    let start = (lo / interval).floor() as i32;
+    let end = (hi / interval).ceil() as i32;
+    (start..=end).map(|k| k as f32 * interval).collect()
+  }
+  fn level_color(&self, index: usize, count: usize) -> [u8; 4] {
+    let t = if count > 1 {
+      index as f32 / (count - 1) as f32
+    } else {
+      0.5
+    };
+    self.colormap.map(t)
+  }
+  fn cell(&self, i: usize, j: usize) -> [Vertex; 4] {
+    // bottom-left, bottom-right, top-right, top-left
+    [
+      (self.x[i], self.y[j], self.z[j][i]),
+      (self.x[i + 1], self.y[j], self.z[j][i + 1]),
+      (self.x[i + 1], self.y[j + 1], self.z[j + 1][i + 1]),
+      (self.x[i], self.y[j + 1], self.z[j + 1][i]),
+    ]
+  }
+  fn cells(&self) -> impl Iterator<Item = [Vertex; 4]> + '_ {
+    (0..self.y.len() - 1).flat_map(move |j| (0..self.x.len() - 1).map(move |i| self.cell(i, j)))
+  }
+
+  /// iso-line segments at `level` with marching squares
+  fn iso_lines(&self, level: f32) -> Vec<(Point, Point)> {
+    let mut segments = Vec::new();
+    for [a, b, c, d] in self.cells() {
+      if [a, b, c, d].iter().any(|v| !v.2.is_finite()) {
+        continue;
+      }
+      let case = (a.2 >= level) as u8
+        | ((b.2 >= level) as u8) << 1
+        | ((c.2 >= level) as u8) << 2
+        | ((d.2 >= level) as u8) << 3;
+      let bottom = || interpolate(a, b, level);
+      let right = || interpolate(b, c, level);
+      let top = || interpolate(d, c, level);
+      let left = || interpolate(a, d, level);
+      let center_high = (a.2 + b.2 + c.2 + d.2) * 0.25 >= level;
+
+      match case {
+        0 | 15 => {}
+        1 | 14 => segments.push((left(), bottom())),
+        2 | 13 => segments.push((bottom(), right())),
+        3 | 12 => segments.push((left(), right())),
+        4 | 11 => segments.push((right(), top())),
+        6 | 9 => segments.push((bottom(), top())),
+        7 | 8 => segments.push((left(), top())),
+        // saddles, decided by the cell center
+        5 if center_high => {
+          segments.push((bottom(), right()));
+          segments.push((left(), top()));
+        }
+        5 => {
+          segments.push((left(), bottom()));
+          segments.push((right(), top()));
+        }
+        10 if center_high => {
+          segments.push((left(), bottom()));
+          segments.push((right(), top()));
+        }
+        10 => {
+          segments.push((bottom(), right()));
+          segments.push((left(), top()));
+        }
+        _ => unreachable!(),
+      }
+    }
+    segments
+  }
+
+  /// polygons where `lo <= z <= hi`, each cell is split in two triangles and clipped
+  fn band(&self, lo: f32, hi: f32) -> Vec<Vec<Point>> {
+    let mut polygons = Vec::new();
+    for [a, b, c, d] in self.cells() {
+      if [a, b, c, d].iter().any(|v| !v.2.is_finite()) {
+        continue;
+      }
+      for tri in [vec![a, b, c], vec![a, c, d]] {
+        let clipped = clip(&clip(&tri, lo, true), hi, false);
+        if clipped.len() >= 3 {
+          polygons.push(clipped.iter().map(|v| Point::from_xy(v.0, v.1)).collect());
+        }
+      }
+    }
+    polygons
+  }
+
+  fn bound(&self) -> Option<Bound> {
+    if !self.is_valid() {
+      return None;
+    }
+    let fold = |v: &[f32]| {
+      v.iter()
+        .fold((f32::INFINITY, f32::NEG_INFINITY), |(lo, hi), &x| {
+          (lo.min(x), hi.max(x))
+        })
+    };
+    let (x_min, x_max) = fold(&self.x);
+    let (y_min, y_max) = fold(&self.y);
+    Some(Bound {
+      x_min,
+      x_max,
+      y_min,
+      y_max,
+    })
+  }
+}
+
+/// point on the edge `p -> q` where z equals `level`
+fn interpolate(p: Vertex, q: Vertex, level: f32) -> Point {
+  let t = if q.2 == p.2 {
+    0.5
+  } else {
+    ((level - p.2) / (q.2 - p.2)).clamp(0.0, 1.0)
+  };
+  Point::from_xy(p.0 + (q.0 - p.0) * t, p.1 + (q.1 - p.1) * t)
+}
+
+/// Sutherland-Hodgman clipping of a polygon against `z >= level` (or `z <= level`)
+fn clip(polygon: &[Vertex], level: f32, keep_above: bool) -> Vec<Vertex> {
+  let inside = |v: &Vertex| {
+    if keep_above {
+      v.2 >= level
+    } else {
+      v.2 <= level
+    }
+  };
+  let mut out = Vec::new();
+  for k in 0..polygon.len() {
+    let cur = polygon[k];
+    let prev = polygon[(k + polygon.len() - 1) % polygon.len()];
+    if inside(&cur) != inside(&prev) {
+      let p = interpolate(prev, cur, level);
+      out.push((p.x, p.y, level));
+    }
+    if inside(&cur) {
+      out.push(cur);
+    }
+  }
+  out
+}
+
+/// format a contour level with as many decimals as the level spacing needs
+fn format_level(level: f32, step: f32) -> String {
+  let decimals = if step > 0.0 {
+    (-step.log10()).ceil().clamp(0.0, 6.0) as usize
+  } else {
+    1
+  };
+  format!("{:.*}", decimals, level)
+}
+
+/// Iso-lines of a scalar field, colored by a colormap
+pub struct Contour {
+  name: String,
+  field: Field,
+  inline_labels: bool,
+  config: Config,
+}
+
+impl Contour {
+  pub fn new(name: String, config: Config) -> Self {
+    Self {
+      name,
+      field: Field::default(),
+      inline_labels: false,
+      config,
+    }
+  }
+  /// Sets the field from grid coordinates and values.
+  ///
+  /// # Arguments
+  ///
+  /// * `x` - The ascending x coordinates of the columns.
+  /// * `y` - The ascending y coordinates of the rows.
+  /// * `z` - The values, `z[j][i]` is the value at `(x[i], y[j])`.
+  pub fn set_data(&mut self, x: &[f32], y: &[f32], z: &[Vec<f32>]) {
+    self.field.set_data(x, y, z);
+  }
+  /// Sets the field by evaluating `f(x, y)` on the grid.
+  pub fn set_fn(&mut self, x: &[f32], y: &[f32], f: impl Fn(f32, f32) -> f32) {
+    self.field.set_fn(x, y, f);
+  }
+  /// Sets the contour levels, by default "nice" levels over the z range are used.
+  pub fn set_levels(&mut self, levels: &[f32]) {
+    self.field.levels = Some(levels.to_vec());
+  }
+  /// Sets the colormap the levels are colored with.
+  pub fn set_colormap(&mut self, colormap: Colormap) {
+    self.field.colormap = colormap;
+  }
+  /// Labels each contour line with its level.
+  pub fn set_inline_labels(&mut self, show: bool) {
+    self.inline_labels = show;
+  }
+}
+
+impl Drawable for Contour {
+  fn draw(&self, pixmap: &mut Pixmap, ts: &Transform, mask: Option<&Mask>) {
+    if self.config.is_hidden || !self.field.is_valid() {
+      return;
+    }
+    let levels = self.field.levels();
+    let stroke = self.config.stroke();
+    for (k, &level) in levels.iter().enumerate() {
+      let mut pb = PathBuilder::new();
+      for (mut p, mut q) in self.field.iso_lines(level) {
+        ts.map_point(&mut p);
+        ts.map_point(&mut q);
+        pb.move_to(p.x, p.y);
+        pb.line_to(q.x, q.y);
+      }
+      if let Some(path) = pb.finish() {
+        let mut config = self.config;
+        config.color = self.field.level_color(k, levels.len());
+        pixmap.stroke_path(&path, &config.paint(), &stroke, Transform::identity(), mask);
+      }
+    }
+  }
+  fn draw_text(
+    &self, pixmap: &mut Pixmap, ts: &Transform, _axes_ts: &Transform, mask: Option<&Mask>,
+    tr: &TextRender,
+  ) {
+    if !self.inline_labels || self.config.is_hidden || !self.field.is_valid() {
+      return;
+    }
+    let levels = self.field.levels();
+    let step = levels
+      .windows(2)
+      .map(|w| w[1] - w[0])
+      .fold(f32::INFINITY, f32::min);
+    let font_size = 10.0;
+    let [r, g, b, a] = color::get_fg();
+    let style = TextStyle {
+      size: font_size,
+      color: Color::from_rgba8(r, g, b, a),
+      ..TextStyle::default()
+    };
+    let [r, g, b, a] = color::get_bg();
+    let mut bg = Paint::default();
+    bg.set_color_rgba8(r, g, b, a);
+
+    for (k, &level) in levels.iter().enumerate() {
+      let segments = self.field.iso_lines(level);
+      // segments run row by row, stagger the labels of the levels so they don't stack up
+      let pick = segments.len() * (2 * k + 1) / (2 * levels.len());
+      let Some((p, q)) = segments.get(pick) else {
+        continue;
+      };
+      let mut mid = Point::from_xy((p.x + q.x) * 0.5, (p.y + q.y) * 0.5);
+      ts.map_point(&mut mid);
+
+      let label = format_level(level, step);
+      let text_w = tr.measure(&label, font_size).0;
+      let (x, y) = (mid.x - text_w / 2.0, mid.y - font_size / 2.0);
+      if let Some(rect) = Rect::from_xywh(x - 2.0, y - 1.0, text_w + 4.0, font_size + 2.0) {
+        pixmap.fill_rect(rect, &bg, Transform::identity(), mask);
+      }
+      tr.draw_styled(pixmap, &label, x, y, &style, mask);
+    }
+  }
+  fn bound(&self) -> Option<Bound> {
+    self.field.bound()
+  }
+  fn name(&self) -> String {
+    self.name.clone()
+  }
+  // not used, colored by the colormap
+  fn get_color(&self) -> [u8; 4] {
+    [255, 255, 255, 255]
+  }
+  fn set_color(&mut self, _color: [u8; 4]) {}
+}
+
+/// Filled bands between consecutive levels of a scalar field, colored by a colormap
+pub struct ContourFilled {
+  name: String,
+  field: Field,
+  config: Config,
+}
+
+impl ContourFilled {
+  pub fn new(name: String, config: Config) -> Self {
+    Self {
+      name,
+      field: Field::default(),
+      config,
+    }
+  }
+  /// Sets the field from grid coordinates and values.
+  ///
+  /// # Arguments
+  ///
+  /// * `x` - The ascending x coordinates of the columns.
+  /// * `y` - The ascending y coordinates of the rows.
+  /// * `z` - The values, `z[j][i]` is the value at `(x[i], y[j])`.
+  pub fn set_data(&mut self, x: &[f32], y: &[f32], z: &[Vec<f32>]) {
+    self.field.set_data(x, y, z);
+  }
+  /// Sets the field by evaluating `f(x, y)` on the grid.
+  pub fn set_fn(&mut self, x: &[f32], y: &[f32], f: impl Fn(f32, f32) -> f32) {
+    self.field.set_fn(x, y, f);
+  }
+  /// Sets the band edges, by default "nice" levels covering the z range are used.
+  pub fn set_levels(&mut self, levels: &[f32]) {
+    self.field.levels = Some(levels.to_vec());
+  }
+  /// Sets the colormap the bands are colored with.
+  pub fn set_colormap(&mut self, colormap: Colormap) {
+    self.field.colormap = colormap;
+  }
+}
+
+impl Drawable for ContourFilled {
+  fn draw(&self, pixmap: &mut Pixmap, ts: &Transform, mask: Option<&Mask>) {
+    if self.config.is_hidden || !self.field.is_valid() {
+      return;
+    }
+    let levels = self.field.levels();
+    let num_bands = levels.len().saturating_sub(1);
+    for k in 0..num_bands {
+      // one path per band, so the shared cell edges are not anti-aliased twice
+      let mut pb = PathBuilder::new();
+      for polygon in self.field.band(levels[k], levels[k + 1]) {
+        for (n, mut p) in polygon.into_iter().enumerate() {
+          ts.map_point(&mut p);
+          if n == 0 {
+            pb.move_to(p.x, p.y);
+          } else {
+            pb.line_to(p.x, p.y);
+          }
+        }
+        pb.close();
+      }
+      if let Some(path) = pb.finish() {
+        let mut config = self.config;
+        config.color = self.field.level_color(k, num_bands);
+        pixmap.fill_path(
+          &path,
+          &config.paint(),
+          FillRule::Winding,
+          Transform::identity(),
+          mask,
+        );
+      }
+    }
+  }
+  fn bound(&self) -> Option<Bound> {
+    self.field.bound()
+  }
+  fn name(&self) -> String {
+    self.name.clone()
+  }
+  // not used, colored by the colormap
+  fn get_color(&self) -> [u8; 4] {
+    [255, 255, 255, 255]
+  }
+  fn set_color(&mut self, _color: [u8; 4]) {}
+}
+
+#[test]
+fn test_iso_lines() {
+  let mut field = Field::default();
+  field.set_fn(&[0., 1., 2.], &[0., 1., 2.], |x, _| x);
+  let segments = field.iso_lines(0.5);
+  assert_eq!(segments.len(), 2);
+  assert!(segments.iter().all(|(p, q)| p.x == 0.5 && q.x == 0.5));
+
+  // the bands of all levels cover the whole grid
+  let area = |poly: &Vec<Point>| {
+    let n = poly.len();
+    (0..n)
+      .map(|k| poly[k].x * poly[(k + 1) % n].y - poly[(k + 1) % n].x * poly[k].y)
+      .sum::<f32>()
+      .abs()
+      / 2.0
+  };
+  let total: f32 = [(0., 0.7), (0.7, 2.)]
+    .iter()
+    .flat_map(|&(lo, hi)| field.band(lo, hi))
+    .map(|p| area(&p))
+    .sum();
+  assert!((total - 4.0).abs() < 1e-4);
+}
@@ -1,12 +1,19 @@
-use tiny_skia::{Paint, PathBuilder, Point, Stroke, Transform};
+use tiny_skia::{LineCap, LineJoin, Mask, PathBuilder, Point, Transform};
 
-use super::config::Config;
-use crate::drawable::{Bound, Drawable};
+use super::{
+  config::Config,
+  uncertainty::{ErrorStyle, Uncertainty},
+};
+use crate::{
+  drawable::{Bound, Drawable},
+  polar::Polar,
+};
 
 pub struct Curve {
   name: String,
   x: Vec<f32>,
   y: Vec<f32>,
+  err: Uncertainty,
   config: Config,
 }
 
@@ -16,6 +23,7 @@ impl Curve {
       name,
       x: Vec::new(),
       y: Vec::new(),
+      err: Uncertainty::default(),
       config,
     }
   }
@@ -57,14 +65,60 @@ impl Curve {
     self.x = t.iter().map(|&v| fx(v)).collect();
     self.y = t.iter().map(|&v| fy(v)).collect();
   }
+  /// Attaches a symmetric error to each y value.
+  ///
+  /// # Arguments
+  ///
+  /// * `err` - The error of each point, drawn from `y - err` to `y + err`.
+  pub fn set_yerr(&mut self, err: &[f32]) {
+    self.err.set_y(err);
+  }
+  /// Attaches an asymmetric error to each y value.
+  ///
+  /// # Arguments
+  ///
+  /// * `lower` - The error below each point.
+  /// * `upper` - The error above each point.
+  pub fn set_yerr_asym(&mut self, lower: &[f32], upper: &[f32]) {
+    self.err.set_y_asym(lower, upper);
+  }
+  /// Attaches a symmetric error to each x value.
+  ///
+  /// # Arguments
+  ///
+  /// * `err` - The error of each point, drawn from `x - err` to `x + err`.
+  pub fn set_xerr(&mut self, err: &[f32]) {
+    self.err.set_x(err);
+  }
+  /// Attaches an asymmetric error to each x value.
+  ///
+  /// # Arguments
+  ///
+  /// * `lower` - The error left of each point.
+  /// * `upper` - The error right of each point.
+  pub fn set_xerr_asym(&mut self, lower: &[f32], upper: &[f32]) {
+    self.err.set_x_asym(lower, upper);
+  }
+  /// Sets how the errors are drawn, default is `ErrorStyle::Bars`.
+  pub fn set_err_style(&mut self, style: ErrorStyle) {
+    self.err.set_style(style);
+  }
+  /// The x/y errors of the points, as set by the methods above.
+  pub fn err_mut(&mut self) -> &mut Uncertainty {
+    &mut self.err
+  }
 }
 
 impl Drawable for Curve {
-  fn draw(&self, pixmap: &mut tiny_skia::Pixmap, ts: &Transform) {
+  fn draw(&self, pixmap: &mut tiny_skia::Pixmap, ts: &Transform, mask: Option<&Mask>) {
     if self.config.is_hidden || self.x.is_empty() || self.y.is_empty() {
       return;
     }
 
+    self
+      .err
+      .draw(pixmap, ts, &self.x, &self.y, &self.config, mask);
+
     let mut pb = PathBuilder::new();
 
     for i in 0..self.x.len() {
@@ -78,21 +132,23 @@ impl Drawable for Curve {
     }
 
     if let Some(path) = pb.finish() {
-      let mut paint = Paint::default();
-      paint.set_color_rgba8(
-        self.config.color[0],
-        self.config.color[1],
-        self.config.color[2],
-        self.config.color[3],
-      );
-      let stroke = Stroke {
-        width: self.config.stroke_width,
-        line_cap: tiny_skia::LineCap::Round,
-        line_join: tiny_skia::LineJoin::Round,
-        ..Stroke::default()
-      };
+      let paint = self.config.paint();
+      let stroke = self.config.stroke_or(LineCap::Round, LineJoin::Round);
 
-      pixmap.stroke_path(&path, &paint, &stroke, Transform::identity(), None);
+      pixmap.stroke_path(&path, &paint, &stroke, Transform::identity(), mask);
+    }
+  }
+  fn draw_polar(&self, pixmap: &mut tiny_skia::Pixmap, polar: &Polar, mask: Option<&Mask>) {
+    if self.config.is_hidden || self.x.is_empty() || self.y.is_empty() {
+      return;
+    }
+    let points: Vec<(f32, f32)> = self.x.iter().cloned().zip(self.y.iter().cloned()).collect();
+    let mut pb = PathBuilder::new();
+    polar.push_polyline(&mut pb, &points);
+    if let Some(path) = pb.finish() {
+      let paint = self.config.paint();
+      let stroke = self.config.stroke_or(LineCap::Round, LineJoin::Round);
+      pixmap.stroke_path(&path, &paint, &stroke, Transform::identity(), mask);
     }
   }
   fn bound(&self) -> Option<Bound> {
@@ -104,12 +160,14 @@ impl Drawable for Curve {
     let y_min = self.y.iter().fold(f32::INFINITY, |a, &b| a.min(b));
     let y_max = self.y.iter().fold(f32::NEG_INFINITY, |a, &b| a.max(b));
 
-    Some(Bound {
+    let mut bound = Bound {
       x_min,
       x_max,
       y_min,
       y_max,
-    })
+    };
+    self.err.extend_bound(&mut bound, &self.x, &self.y);
+    Some(bound)
   }
   fn name(&self) -> String {
     self.name.clone()
@@ -121,3 +179,17 @@ impl Drawable for Curve {
     self.config.color = color;
   }
 }
+
+#[test]
+fn test_curve_errors() {
+  let mut curve = Curve::new("c".to_string(), Config::default());
+  curve.set_data(&[0.0, 1.0], &[1.0, 2.0]);
+  curve.set_yerr_asym(&[0.5, 0.5], &[1.0, 1.0]);
+  curve.set_xerr(&[0.25, 0.25]);
+  // the bound covers the error bars
+  let b = curve.bound().unwrap();
+  assert_eq!(
+    (b.x_min, b.x_max, b.y_min, b.y_max),
+    (-0.25, 1.25, 0.5, 3.0)
+  );
+}
@@ -1,6 +1,6 @@
 use core::f32;
 
-use tiny_skia::{Paint, PathBuilder, Pixmap, Point, Stroke, Transform};
+use tiny_skia::{Mask, PathBuilder, Pixmap, Point, Transform};
 
 use crate::primitive::{Config, Histrogram};
 use crate::{
@@ -30,15 +30,6 @@ impl Bar {
       config,
     }
   }
-  fn or(mut self, rhs: f32) -> Self {
-    let mean = (self.mean + rhs) / 2.;
-    let min = self.min.min(rhs);
-    let max = self.max.max(rhs);
-    self.mean = mean;
-    self.min = min;
-    self.max = max;
-    self
-  }
 }
 
 pub struct ErrorBar {
@@ -176,17 +167,13 @@ impl ErrorBar {
 
 // implement for draw
 impl ErrorBar {
-  fn draw_internal(&self, pixmap: &mut Pixmap, ts: &Transform, mode: &ErrorBarType) {
+  fn draw_internal(
+    &self, pixmap: &mut Pixmap, ts: &Transform, mode: &ErrorBarType, mask: Option<&Mask>,
+  ) {
     if self.bars.is_empty() {
       return;
     }
 
-    let mut paint = Paint::default();
-    let stroke = Stroke {
-      width: 2.,
-      ..Stroke::default()
-    };
-
     for (index, bar) in self.bars.iter().enumerate() {
       if bar.config.is_hidden {
         continue;
@@ -216,9 +203,8 @@ impl ErrorBar {
       ts.map_point(&mut end);
       ts.map_point(&mut mean);
 
-      let [r, g, b, a] = bar.config.color;
-      paint.set_color_rgba8(r, g, b, a);
-      paint.anti_alias = true;
+      let paint = bar.config.paint();
+      let stroke = bar.config.stroke();
 
       // 绘制均值圆
       if let Some(circle) = PathBuilder::from_circle(mean.x, mean.y, 6.) {
@@ -227,7 +213,7 @@ impl ErrorBar {
           &paint,
           tiny_skia::FillRule::Winding,
           Transform::identity(),
-          None,
+          mask,
         );
       }
 
@@ -254,7 +240,7 @@ impl ErrorBar {
       }
 
       if let Some(path) = pb.finish() {
-        pixmap.stroke_path(&path, &paint, &stroke, Transform::identity(), None);
+        pixmap.stroke_path(&path, &paint, &stroke, Transform::identity(), mask);
       }
     }
   }
@@ -307,8 +293,8 @@ impl ErrorBar {
 }
 
 impl Drawable for ErrorBar {
-  fn draw(&self, pixmap: &mut tiny_skia::Pixmap, ts: &tiny_skia::Transform) {
-    self.draw_internal(pixmap, ts, &self.err_type);
+  fn draw(&self, pixmap: &mut tiny_skia::Pixmap, ts: &tiny_skia::Transform, mask: Option<&Mask>) {
+    self.draw_internal(pixmap, ts, &self.err_type, mask);
   }
   fn bound(&self) -> Option<crate::drawable::Bound> {
     match self.err_type {
@@ -1,19 +1,67 @@
 use crate::color;
-use tiny_skia::{Paint, PathBuilder, Pixmap, Point, Rect, Stroke, Transform};
+use tiny_skia::{Color, FillRule, Mask, Paint, PathBuilder, Pixmap, Rect, Transform};
 
 use crate::{
   drawable::{Bound, Drawable},
-  primitive::Config,
+  polar::Polar,
+  primitive::{Bins, Config, Orientation, bins},
+  text_render::{TextRender, TextStyle},
 };
 
+/// default part of a slot covered by the groups drawn in it
+pub(crate) const GROUP_WIDTH_RATIO: f32 = 0.8;
+
+/// offset from the slot center and width of group `g_idx` out of `num_groups`
+/// groups placed side by side, covering `ratio` of a slot of width `slot_w`
+pub(crate) fn slot_offset(g_idx: usize, num_groups: usize, slot_w: f32, ratio: f32) -> (f32, f32) {
+  let single_w = slot_w * ratio / num_groups as f32;
+  let half_num_groups = (num_groups as f32 - 1.0) / 2.0;
+  ((g_idx as f32 - half_num_groups) * single_w, single_w)
+}
+
+/// How each data series of a histogram is drawn
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum HistStyle {
+  /// series side by side in each slot
+  #[default]
+  Bar,
+  /// outline of each series over the full bin width, for overlapping distributions
+  Step,
+  /// like `Step`, filled with the half transparent series color
+  StepFilled,
+}
+
+/// How several series share a slot in the `HistStyle::Bar` style
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum BarMode {
+  /// side by side
+  #[default]
+  Grouped,
+  /// on top of each other, negative values stack below 0
+  Stacked,
+  /// stacked and scaled so each slot sums to 100
+  PercentStacked,
+}
+
+/// raw samples a series was binned from
+struct Samples {
+  values: Vec<f32>,
+  weights: Option<Vec<f32>>,
+}
+
 pub(crate) struct Bars {
   y: Vec<f32>,
+  samples: Option<Samples>,
 
   config: Config,
 }
 impl Bars {
   pub fn new(y: Vec<f32>, config: Config) -> Self {
-    Self { y, config }
+    Self {
+      y,
+      samples: None,
+      config,
+    }
   }
 }
 
@@ -22,6 +70,16 @@ pub struct Histrogram {
   x: Vec<f32>,
   bars: Vec<Bars>,
 
+  bins: Bins,
+  density: bool,
+  cumulative: bool,
+  style: HistStyle,
+  mode: BarMode,
+  orientation: Orientation,
+  bar_width: f32,
+  bar_gap: f32,
+  value_labels: bool,
+
   color_index: usize,
 }
 
@@ -31,9 +89,31 @@ impl Histrogram {
       name,
       x: Vec::new(),
       bars: Vec::new(),
+      bins: Bins::default(),
+      density: false,
+      cumulative: false,
+      style: HistStyle::Bar,
+      mode: BarMode::Grouped,
+      orientation: Orientation::Vertical,
+      bar_width: GROUP_WIDTH_RATIO,
+      bar_gap: 0.0,
+      value_labels: false,
       color_index: 0,
     }
   }
+  /// Creates a histogram from raw samples, the bins are computed by `bins`.
+  ///
+  /// # Arguments
+  ///
+  /// * `name` - The name identifier for the histogram.
+  /// * `samples` - The raw samples, non-finite values (`NaN`, `±inf`) are ignored.
+  /// * `bins` - How the bin edges are computed.
+  pub fn from_samples(name: String, samples: &[f32], bins: Bins) -> Self {
+    let mut his = Self::new(name);
+    his.bins = bins;
+    his.add_samples(samples);
+    his
+  }
   fn max_len(&self) -> usize {
     self.x.len().saturating_sub(1)
   }
@@ -179,6 +259,173 @@ impl Histrogram {
   }
 }
 
+// layout options
+impl Histrogram {
+  /// Sets how several series share a slot, default is `BarMode::Grouped`.
+  pub fn set_mode(&mut self, mode: BarMode) {
+    self.mode = mode;
+  }
+  /// Sets the orientation, `Orientation::Horizontal` puts the slots on the y axis.
+  pub fn set_orientation(&mut self, orientation: Orientation) {
+    self.orientation = orientation;
+  }
+  /// Sets the part of a slot covered by its bars, in `(0, 1]`, default is `0.8`.
+  pub fn set_bar_width(&mut self, width: f32) {
+    self.bar_width = width.clamp(0.05, 1.0);
+  }
+  /// Sets the gap between grouped bars, relative to a single bar width, in `[0, 1)`.
+  pub fn set_bar_gap(&mut self, gap: f32) {
+    self.bar_gap = gap.clamp(0.0, 0.95);
+  }
+  /// Shows the value of each bar next to it.
+  pub fn set_value_labels(&mut self, show: bool) {
+    self.value_labels = show;
+  }
+}
+
+// binning from raw samples
+impl Histrogram {
+  /// Adds a data series binned from raw samples.
+  ///
+  /// The bin edges are recomputed from all sample series, so several distributions
+  /// share the same bins. Do not mix with `set_x`/`set_data`.
+  pub fn add_samples(&mut self, samples: &[f32]) {
+    self.push_samples(samples, None);
+  }
+  /// Adds a data series binned from raw samples, each sample counting with its weight.
+  ///
+  /// # Arguments
+  ///
+  /// * `samples` - The raw samples, non-finite values (`NaN`, `±inf`) are ignored.
+  /// * `weights` - The weight of each sample, missing weights count as `1.0`.
+  pub fn add_weighted_samples(&mut self, samples: &[f32], weights: &[f32]) {
+    self.push_samples(samples, Some(weights));
+  }
+  /// Sets how the bin edges are computed, default is `Bins::Count(10)`.
+  pub fn set_bins(&mut self, bins: Bins) {
+    self.bins = bins;
+    self.rebin();
+  }
+  /// Normalises each series so its area is 1 (or ends at 1 when cumulative).
+  pub fn set_density(&mut self, density: bool) {
+    self.density = density;
+    self.rebin();
+  }
+  /// Accumulates the counts from the left to the right bin.
+  pub fn set_cumulative(&mut self, cumulative: bool) {
+    self.cumulative = cumulative;
+    self.rebin();
+  }
+  /// Sets how each series is drawn, default is `HistStyle::Bar`.
+  pub fn set_style(&mut self, style: HistStyle) {
+    self.style = style;
+  }
+  fn push_samples(&mut self, samples: &[f32], weights: Option<&[f32]>) {
+    let config = self.gen_config();
+    let mut bar = Bars::new(Vec::new(), config);
+    bar.samples = Some(Samples {
+      values: samples.to_vec(),
+      weights: weights.map(|w| w.to_vec()),
+    });
+    self.bars.push(bar);
+    self.rebin();
+  }
+  /// recompute the edges and the values of all series binned from samples
+  fn rebin(&mut self) {
+    let mut all: Vec<f32> = self
+      .bars
+      .iter()
+      .filter_map(|b| b.samples.as_ref())
+      .flat_map(|s| s.values.iter().cloned())
+      .filter(|v| v.is_finite())
+      .collect();
+    if all.is_empty() {
+      return;
+    }
+    all.sort_by(|a, b| a.total_cmp(b));
+    self.x = self.bins.edges(&all);
+
+    for bar in self.bars.iter_mut() {
+      let Some(samples) = &bar.samples else {
+        continue;
+      };
+      let counts = bins::count(&self.x, &samples.values, samples.weights.as_deref());
+      let total: f32 = counts.iter().sum();
+      bar.y = if self.cumulative {
+        let mut acc = 0.0;
+        counts
+          .iter()
+          .map(|c| {
+            acc += c;
+            if self.density && total > 0.0 {
+              acc / total
+            } else {
+              acc
+            }
+          })
+          .collect()
+      } else if self.density && total > 0.0 {
+        counts
+          .iter()
+          .zip(self.x.windows(2))
+          .map(|(c, e)| c / (total * (e[1] - e[0])))
+          .collect()
+      } else {
+        counts
+      };
+    }
+  }
+  /// binned histograms center the slot on the bin, data set by hand keeps it on the right edge
+  fn slot_center(&self, i: usize) -> f32 {
+    if self.bars.iter().any(|b| b.samples.is_some()) {
+      (self.x[i] + self.x[i + 1]) * 0.5
+    } else {
+      self.x[i + 1]
+    }
+  }
+  fn draw_steps(&self, pixmap: &mut Pixmap, ts: &Transform, mask: Option<&Mask>) {
+    for bar in self.bars.iter().filter(|b| !b.config.is_hidden) {
+      let n = bar.y.len().min(self.x.len() - 1);
+      if n == 0 {
+        continue;
+      }
+      let map = |x: f32, y: f32| {
+        let mut p = self.orientation.point(x, y);
+        ts.map_point(&mut p);
+        p
+      };
+      let mut pb = PathBuilder::new();
+      let p = map(self.x[0], 0.0);
+      pb.move_to(p.x, p.y);
+      for i in 0..n {
+        let (l, r) = (map(self.x[i], bar.y[i]), map(self.x[i + 1], bar.y[i]));
+        pb.line_to(l.x, l.y);
+        pb.line_to(r.x, r.y);
+      }
+      let p = map(self.x[n], 0.0);
+      pb.line_to(p.x, p.y);
+
+      let Some(path) = pb.finish() else {
+        continue;
+      };
+      let paint = bar.config.paint();
+      if self.style == HistStyle::StepFilled {
+        let mut fill = paint.clone();
+        let [r, g, b, a] = bar.config.rgba();
+        fill.set_color_rgba8(r, g, b, a / 2);
+        pixmap.fill_path(&path, &fill, FillRule::Winding, Transform::identity(), mask);
+      }
+      pixmap.stroke_path(
+        &path,
+        &paint,
+        &bar.config.stroke(),
+        Transform::identity(),
+        mask,
+      );
+    }
+  }
+}
+
 // with errorbar
 impl Histrogram {
   pub(crate) fn get_bars(&self) -> &[Bars] {
@@ -191,78 +438,202 @@ impl Bars {
   }
 }
 
+// bar layout for the `HistStyle::Bar` style
+impl Histrogram {
+  /// place every non-zero value of the visible series as a bar
+  fn layout(&self) -> Vec<BarRect> {
+    let mut rects = Vec::new();
+    if self.x.len() < 2 {
+      return rects;
+    }
+    let num_groups = self.bars.len();
+
+    // 遍历槽位：i 是槽位索引
+    for i in 0..(self.x.len() - 1) {
+      let total_step_w = self.x[i + 1] - self.x[i];
+      let center = self.slot_center(i);
+      let value = |bar: &Bars| bar.y.get(i).cloned().unwrap_or(0.0);
+
+      match self.mode {
+        BarMode::Grouped => {
+          for (g_idx, bar) in self.bars.iter().enumerate() {
+            let v = value(bar);
+            // 0. not draw
+            if bar.config.is_hidden || v == 0.0 {
+              continue;
+            }
+            let (offset, w) = slot_offset(g_idx, num_groups, total_step_w, self.bar_width);
+            let half = w * (1.0 - self.bar_gap) * 0.5;
+            rects.push(BarRect {
+              series: g_idx,
+              l: center + offset - half,
+              r: center + offset + half,
+              base: 0.0,
+              top: v,
+              value: v,
+            });
+          }
+        }
+        BarMode::Stacked | BarMode::PercentStacked => {
+          let scale = if self.mode == BarMode::PercentStacked {
+            let total: f32 = self
+              .bars
+              .iter()
+              .filter(|b| !b.config.is_hidden)
+              .map(|b| value(b).abs())
+              .sum();
+            if total == 0.0 { 0.0 } else { 100.0 / total }
+          } else {
+            1.0
+          };
+          let half = total_step_w * self.bar_width * 0.5;
+          // positive values stack up from 0, negative values stack down
+          let (mut pos, mut neg) = (0.0, 0.0);
+          for (g_idx, bar) in self.bars.iter().enumerate() {
+            let v = value(bar);
+            if bar.config.is_hidden || v == 0.0 {
+              continue;
+            }
+            let acc = if v > 0.0 { &mut pos } else { &mut neg };
+            let base = *acc;
+            *acc += v * scale;
+            rects.push(BarRect {
+              series: g_idx,
+              l: center - half,
+              r: center + half,
+              base,
+              top: *acc,
+              value: v * scale,
+            });
+          }
+        }
+      }
+    }
+    rects
+  }
+}
+
+struct BarRect {
+  series: usize,
+  /// position of both sides on the category axis
+  l: f32,
+  r: f32,
+  /// value range covered by the bar
+  base: f32,
+  top: f32,
+  /// value shown by the label
+  value: f32,
+}
+
 impl Drawable for Histrogram {
-  fn draw(&self, pixmap: &mut Pixmap, ts: &Transform) {
+  fn draw(&self, pixmap: &mut Pixmap, ts: &Transform, mask: Option<&Mask>) {
     // 只有当至少有两个刻度（一个槽位）且有数据组时才绘制
     if self.x.len() < 2 || self.bars.is_empty() {
       return;
     }
 
-    let num_groups = self.bars.len() as f32;
-    let group_width_ratio = 0.8;
+    if self.style != HistStyle::Bar {
+      self.draw_steps(pixmap, ts, mask);
+      return;
+    }
+
+    for rect in self.layout() {
+      let bar = &self.bars[rect.series];
+      let mut p1 = self.orientation.point(rect.l, rect.top);
+      let mut p2 = self.orientation.point(rect.r, rect.base);
+      ts.map_point(&mut p1);
+      ts.map_point(&mut p2);
 
-    // constants
-    let half_num_groups = (num_groups - 1.0) / 2.0;
+      // draw rect
+      if let Some(r_rect) = Rect::from_ltrb(
+        p1.x.min(p2.x),
+        p1.y.min(p2.y),
+        p1.x.max(p2.x),
+        p1.y.max(p2.y),
+      ) {
+        let [r, g, b, a] = bar.config.rgba();
+        let paint = bar.config.paint();
 
-    // 遍历槽位：i 是槽位索引，x_start/x_end is the start/end of the slot
-    for i in 0..(self.x.len() - 1) {
-      let x_start = self.x[i];
-      let x_end = self.x[i + 1];
-      let total_step_w = x_end - x_start;
-      // shift half of total_step_w
-      let x_start = x_start + total_step_w * 0.5;
-      let x_center = x_start + total_step_w * 0.5;
-      let single_bar_w = (total_step_w * group_width_ratio) / num_groups;
-
-      for (g_idx, bar) in self.bars.iter().enumerate() {
-        if bar.config.is_hidden {
+        pixmap.fill_rect(r_rect, &paint, Transform::identity(), mask);
+
+        // border
+        let path = PathBuilder::from_rect(r_rect);
+        let mut stroke_paint = Paint::default();
+        stroke_paint.set_color_rgba8(
+          r.saturating_sub(40),
+          g.saturating_sub(40),
+          b.saturating_sub(40),
+          a,
+        );
+        let stroke = bar.config.stroke();
+        pixmap.stroke_path(&path, &stroke_paint, &stroke, Transform::identity(), mask);
+      }
+    }
+  }
+
+  /// bars become wedges (e.g. a wind rose), the orientation is not used
+  fn draw_polar(&self, pixmap: &mut Pixmap, polar: &Polar, mask: Option<&Mask>) {
+    if self.x.len() < 2 || self.bars.is_empty() {
+      return;
+    }
+    if self.style != HistStyle::Bar {
+      for bar in self.bars.iter().filter(|b| !b.config.is_hidden) {
+        let n = bar.y.len().min(self.x.len() - 1);
+        if n == 0 {
           continue;
         }
-
-        let y_val = bar.y[i];
-        if y_val == 0.0 {
+        let mut points = vec![(self.x[0], 0.0)];
+        for i in 0..n {
+          points.push((self.x[i], bar.y[i]));
+          points.push((self.x[i + 1], bar.y[i]));
+        }
+        points.push((self.x[n], 0.0));
+        let mut pb = PathBuilder::new();
+        polar.push_polyline(&mut pb, &points);
+        let Some(path) = pb.finish() else {
           continue;
-        } // 0. not draw
-
-        let offset = (g_idx as f32 - half_num_groups) * single_bar_w;
-        let x_l = x_center + offset - single_bar_w * 0.5;
-        let x_r = x_center + offset + single_bar_w * 0.5;
-
-        // draw rect
-        let mut p1 = Point::from_xy(x_l, y_val);
-        let mut p2 = Point::from_xy(x_r, 0.0);
-        ts.map_point(&mut p1);
-        ts.map_point(&mut p2);
-
-        if let Some(r_rect) = Rect::from_ltrb(
-          p1.x.min(p2.x),
-          p1.y.min(p2.y),
-          p1.x.max(p2.x),
-          p1.y.max(p2.y),
-        ) {
-          let [r, g, b, a] = bar.config.color;
-          let mut paint = Paint::default();
-          paint.set_color_rgba8(r, g, b, a);
-          paint.anti_alias = true;
-
-          pixmap.fill_rect(r_rect, &paint, Transform::identity(), None);
-
-          // border
-          let path = PathBuilder::from_rect(r_rect);
-          let mut stroke_paint = Paint::default();
-          stroke_paint.set_color_rgba8(
-            r.saturating_sub(40),
-            g.saturating_sub(40),
-            b.saturating_sub(40),
-            255,
-          );
-          let stroke = Stroke {
-            width: bar.config.stroke_width,
-            ..Default::default()
-          };
-          pixmap.stroke_path(&path, &stroke_paint, &stroke, Transform::identity(), None);
+        };
+        let paint = bar.config.paint();
+        if self.style == HistStyle::StepFilled {
+          let mut fill = paint.clone();
+          let [r, g, b, a] = bar.config.rgba();
+          fill.set_color_rgba8(r, g, b, a / 2);
+          pixmap.fill_path(&path, &fill, FillRule::Winding, Transform::identity(), mask);
         }
+        pixmap.stroke_path(
+          &path,
+          &paint,
+          &bar.config.stroke(),
+          Transform::identity(),
+          mask,
+        );
       }
+      return;
+    }
+    for rect in self.layout() {
+      let bar = &self.bars[rect.series];
+      let mut pb = PathBuilder::new();
+      polar.push_wedge(&mut pb, (rect.l, rect.r), (rect.base, rect.top));
+      let Some(path) = pb.finish() else {
+        continue;
+      };
+      let [r, g, b, a] = bar.config.rgba();
+      pixmap.fill_path(
+        &path,
+        &bar.config.paint(),
+        FillRule::Winding,
+        Transform::identity(),
+        mask,
+      );
+      let mut stroke_paint = Paint::default();
+      stroke_paint.set_color_rgba8(
+        r.saturating_sub(40),
+        g.saturating_sub(40),
+        b.saturating_sub(40),
+        a,
+      );
+      let stroke = bar.config.stroke();
+      pixmap.stroke_path(&path, &stroke_paint, &stroke, Transform::identity(), mask);
     }
   }
 
@@ -271,32 +642,95 @@ impl Drawable for Histrogram {
       return None;
     }
 
-    let mut y_max = 0.0f32;
-    let mut y_min = 0.0f32;
+    let mut v_max = 0.0f32;
+    let mut v_min = 0.0f32;
     let mut has_data = false;
 
-    for bar in self.bars.iter() {
-      if bar.config.is_hidden {
-        continue;
-      }
-      for &y in &bar.y {
-        y_max = y_max.max(y);
-        y_min = y_min.min(y);
+    if self.style == HistStyle::Bar && self.mode != BarMode::Grouped {
+      for rect in self.layout() {
+        v_max = v_max.max(rect.base.max(rect.top));
+        v_min = v_min.min(rect.base.min(rect.top));
         has_data = true;
       }
+    } else {
+      for bar in self.bars.iter() {
+        if bar.config.is_hidden {
+          continue;
+        }
+        for &y in &bar.y {
+          v_max = v_max.max(y);
+          v_min = v_min.min(y);
+          has_data = true;
+        }
+      }
     }
 
     if !has_data {
       return None;
     }
 
-    Some(Bound {
-      x_min: *self.x.first().unwrap(),
-      x_max: *self.x.last().unwrap(),
-      y_min,
-      y_max,
+    let (mut p_min, mut p_max) = (*self.x.first().unwrap(), *self.x.last().unwrap());
+    if self.style == HistStyle::Bar {
+      // bars of data set by hand are centered on the slot edges, the last one overhangs x
+      for rect in self.layout() {
+        p_min = p_min.min(rect.l);
+        p_max = p_max.max(rect.r);
+      }
+    }
+    Some(match self.orientation {
+      Orientation::Vertical => Bound {
+        x_min: p_min,
+        x_max: p_max,
+        y_min: v_min,
+        y_max: v_max,
+      },
+      Orientation::Horizontal => Bound {
+        x_min: v_min,
+        x_max: v_max,
+        y_min: p_min,
+        y_max: p_max,
+      },
     })
   }
+  fn draw_text(
+    &self, pixmap: &mut Pixmap, ts: &Transform, _axes_ts: &Transform, mask: Option<&Mask>,
+    tr: &TextRender,
+  ) {
+    if !self.value_labels || self.style != HistStyle::Bar {
+      return;
+    }
+    let font_size = 10.0;
+    let [r, g, b, a] = color::get_fg();
+    let style = TextStyle {
+      size: font_size,
+      color: Color::from_rgba8(r, g, b, a),
+      ..TextStyle::default()
+    };
+    let stacked = self.mode != BarMode::Grouped;
+
+    for rect in self.layout() {
+      let label = format!("{:.1}", rect.value);
+      let text_w = tr.measure(&label, font_size).0;
+      let pos = (rect.l + rect.r) * 0.5;
+      // stacked segments are labelled in their middle, single bars at their end
+      let val = if stacked {
+        (rect.base + rect.top) * 0.5
+      } else {
+        rect.top
+      };
+      let mut p = self.orientation.point(pos, val);
+      ts.map_point(&mut p);
+
+      let (x, y) = match (self.orientation, stacked) {
+        (_, true) => (p.x - text_w / 2.0, p.y - font_size / 2.0),
+        (Orientation::Vertical, false) if val >= 0.0 => (p.x - text_w / 2.0, p.y - font_size - 2.0),
+        (Orientation::Vertical, false) => (p.x - text_w / 2.0, p.y + 2.0),
+        (Orientation::Horizontal, false) if val >= 0.0 => (p.x + 4.0, p.y - font_size / 2.0),
+        (Orientation::Horizontal, false) => (p.x - text_w - 4.0, p.y - font_size / 2.0),
+      };
+      tr.draw_styled(pixmap, &label, x, y, &style, mask);
+    }
+  }
   fn name(&self) -> String {
     self.name.clone()
   }
@@ -307,3 +741,108 @@ impl Drawable for Histrogram {
   /// not used in this primitive
   fn set_color(&mut self, _color: [u8; 4]) {}
 }
+
+#[test]
+fn test_value_labels_clipped() {
+  use crate::axis::Axis;
+
+  let tr = TextRender::new();
+  let mut axis = Axis::new(0.0, 0.0, (400.0, 300.0));
+  let mut his = Histrogram::new("h".to_string());
+  his.set_data_norm(&[3.0, 8.0, 5.0]);
+  his.set_value_labels(true);
+  axis.add(Box::new(his));
+  axis.set_x_limit(Some((0.0, 3.0)));
+  axis.set_y_limit(Some((0.0, 7.5)));
+  let mut pixmap = Pixmap::new(400, 300).unwrap();
+  axis.render(&mut pixmap, &tr);
+
+  // the label of the bar above the limit would be in the margin over the plot area
+  let plot = axis.plot_rect().unwrap();
+  for y in 0..(plot.top() - 2.0) as u32 {
+    for x in (plot.left() + 6.0) as u32..plot.right() as u32 {
+      assert_eq!(pixmap.pixel(x, y).unwrap().alpha(), 0, "({x}, {y})");
+    }
+  }
+}
+
+#[test]
+fn test_stacked_layout() {
+  let mut his = Histrogram::new("h".to_string());
+  his.set_data_norm(&[2.0, -1.0]);
+  his.set_data(&[3.0, -3.0]);
+  his.set_bar_width(0.5);
+  let rects = |his: &Histrogram| -> Vec<_> {
+    let layout = his.layout();
+    layout
+      .iter()
+      .map(|r| (r.series, r.l, r.r, r.base, r.top))
+      .collect()
+  };
+
+  // manual data is centered on the right edge of each slot, negative values stack down
+  his.set_mode(BarMode::Stacked);
+  assert_eq!(
+    rects(&his),
+    [
+      (0, 0.75, 1.25, 0.0, 2.0),
+      (1, 0.75, 1.25, 2.0, 5.0),
+      (0, 1.75, 2.25, 0.0, -1.0),
+      (1, 1.75, 2.25, -1.0, -4.0)
+    ]
+  );
+  let b = his.bound().unwrap();
+  assert_eq!((b.x_min, b.x_max, b.y_min, b.y_max), (0.0, 2.25, -4.0, 5.0));
+
+  // each slot sums to 100 in absolute value
+  his.set_mode(BarMode::PercentStacked);
+  assert_eq!(
+    rects(&his),
+    [
+      (0, 0.75, 1.25, 0.0, 40.0),
+      (1, 0.75, 1.25, 40.0, 100.0),
+      (0, 1.75, 2.25, 0.0, -25.0),
+      (1, 1.75, 2.25, -25.0, -100.0)
+    ]
+  );
+
+  // a horizontal layout swaps the axes of the bound
+  his.set_orientation(Orientation::Horizontal);
+  let b = his.bound().unwrap();
+  assert_eq!(
+    (b.x_min, b.x_max, b.y_min, b.y_max),
+    (-100.0, 100.0, 0.0, 2.25)
+  );
+}
+
+#[test]
+fn test_last_bar_in_limit() {
+  use crate::axis::Axis;
+
+  let tr = TextRender::new();
+  for orientation in [Orientation::Vertical, Orientation::Horizontal] {
+    let mut axis = Axis::new(0.0, 0.0, (400.0, 300.0));
+    let mut his = Histrogram::new("h".to_string());
+    his.set_data_norm(&[3.0, 8.0, 5.0]);
+    his.set_orientation(orientation);
+    axis.add(Box::new(his));
+    let mut pixmap = Pixmap::new(400, 300).unwrap();
+    axis.render(&mut pixmap, &tr);
+
+    // the last bar is centered on x = 3 and 0.4 wide on each side
+    let limit = match orientation {
+      Orientation::Vertical => axis.x_limit(),
+      Orientation::Horizontal => axis.y_limit(),
+    };
+    assert!(limit.unwrap().1 >= 3.4, "{orientation:?}");
+  }
+}
+
+#[test]
+fn test_infinite_samples() {
+  let samples = [1.0, f32::INFINITY, 2.0, f32::NAN, f32::NEG_INFINITY, 3.0];
+  let his = Histrogram::from_samples("h".to_string(), &samples, Bins::Count(2));
+  // the bins span the finite samples, which are the only ones counted
+  assert_eq!(his.x, [1.0, 2.0, 3.0]);
+  assert_eq!(his.bars[0].y, [1.0, 2.0]);
+}
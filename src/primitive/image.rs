@@ -0,0 +1,225 @@
+use tiny_skia::{ColorU8, FilterQuality, Mask, Pixmap, PixmapPaint, Transform};
+
+use crate::{
+  color::Colormap,
+  drawable::{Bound, Drawable},
+  primitive::Config,
+};
+
+/// How the image is resampled when it is scaled
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum Interpolation {
+  /// sharp pixels
+  #[default]
+  Nearest,
+  Bilinear,
+}
+
+/// Where the first row of the image is placed
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum Origin {
+  /// the first row is at the top of the extent, like a picture
+  #[default]
+  Upper,
+  /// the first row is at the bottom of the extent, like a matrix indexed by `(x, y)`
+  Lower,
+}
+
+/// source of the image pixels, kept so the colormap and limits can change later
+enum Source {
+  Rgba,
+  Scalar(Vec<Vec<f32>>),
+}
+
+/// Raster image placed into data coordinates (imshow)
+/// * drawn as a single pixmap pattern, so large images don't cost one rect per pixel
+/// * with `ScaleStrategy::Fit` and an extent matching the image size the pixels stay square
+pub struct Image {
+  name: String,
+  source: Source,
+  pixmap: Option<Pixmap>,
+  /// `(x_min, x_max, y_min, y_max)`, `None` is `(0, width, 0, height)`
+  extent: Option<(f32, f32, f32, f32)>,
+  interpolation: Interpolation,
+  origin: Origin,
+
+  colormap: Colormap,
+  limit: Option<(f32, f32)>,
+
+  config: Config,
+}
+
+impl Image {
+  pub fn new(name: String, config: Config) -> Self {
+    Self {
+      name,
+      source: Source::Rgba,
+      pixmap: None,
+      extent: None,
+      interpolation: Interpolation::default(),
+      origin: Origin::default(),
+      colormap: Colormap::default(),
+      limit: None,
+      config,
+    }
+  }
+  /// Sets the image from a RGBA buffer.
+  ///
+  /// # Arguments
+  ///
+  /// * `width` - The number of pixels per row.
+  /// * `height` - The number of rows.
+  /// * `data` - `width * height` pixels, row by row, 4 bytes each, not premultiplied.
+  pub fn set_rgba(&mut self, width: u32, height: u32, data: &[u8]) {
+    self.source = Source::Rgba;
+    self.pixmap = Pixmap::new(width, height);
+    if let Some(pixmap) = &mut self.pixmap {
+      for (px, rgba) in pixmap.pixels_mut().iter_mut().zip(data.chunks_exact(4)) {
+        *px = ColorU8::from_rgba(rgba[0], rgba[1], rgba[2], rgba[3]).premultiply();
+      }
+    }
+  }
+  /// Sets the image from a matrix of values, colored by the colormap.
+  ///
+  /// # Arguments
+  ///
+  /// * `z` - The values row by row, as long as the first row, `NaN` is transparent.
+  pub fn set_data(&mut self, z: &[Vec<f32>]) {
+    self.source = Source::Scalar(z.to_vec());
+    self.colorize();
+  }
+  /// Sets the colormap used for matrix data.
+  pub fn set_colormap(&mut self, colormap: Colormap) {
+    self.colormap = colormap;
+    self.colorize();
+  }
+  /// Sets the values mapped to both ends of the colormap, `None` uses the data range.
+  pub fn set_limit(&mut self, limit: Option<(f32, f32)>) {
+    self.limit = limit;
+    self.colorize();
+  }
+  /// Sets the rectangle the image covers in data coordinates.
+  ///
+  /// # Arguments
+  ///
+  /// * `extent` - `(x_min, x_max, y_min, y_max)`, `None` puts each pixel on a unit square.
+  pub fn set_extent(&mut self, extent: Option<(f32, f32, f32, f32)>) {
+    self.extent = extent;
+  }
+  /// Sets how the image is resampled, default is `Interpolation::Nearest`.
+  pub fn set_interpolation(&mut self, interpolation: Interpolation) {
+    self.interpolation = interpolation;
+  }
+  /// Sets where the first row is placed, default is `Origin::Upper`.
+  pub fn set_origin(&mut self, origin: Origin) {
+    self.origin = origin;
+  }
+
+  /// rebuild the pixmap from scalar data
+  fn colorize(&mut self) {
+    let Source::Scalar(z) = &self.source else {
+      return;
+    };
+    let width = z.first().map(|row| row.len()).unwrap_or(0);
+    let Some(mut pixmap) = Pixmap::new(width as u32, z.len() as u32) else {
+      self.pixmap = None;
+      return;
+    };
+    let (lo, hi) = self.limit.unwrap_or_else(|| {
+      z.iter()
+        .flatten()
+        .filter(|v| v.is_finite())
+        .fold((f32::INFINITY, f32::NEG_INFINITY), |(lo, hi), &v| {
+          (lo.min(v), hi.max(v))
+        })
+    });
+    let range = if hi > lo { hi - lo } else { 1.0 };
+
+    for (j, row) in z.iter().enumerate() {
+      for (i, &v) in row.iter().take(width).enumerate() {
+        let [r, g, b, a] = if v.is_finite() {
+          self.colormap.map((v - lo) / range)
+        } else {
+          [0, 0, 0, 0]
+        };
+        pixmap.pixels_mut()[j * width + i] = ColorU8::from_rgba(r, g, b, a).premultiply();
+      }
+    }
+    self.pixmap = Some(pixmap);
+  }
+  fn extent(&self, pixmap: &Pixmap) -> (f32, f32, f32, f32) {
+    self
+      .extent
+      .unwrap_or((0.0, pixmap.width() as f32, 0.0, pixmap.height() as f32))
+  }
+  /// map image pixel coordinates into data coordinates
+  fn pixel_ts(&self, pixmap: &Pixmap) -> Transform {
+    let (x_min, x_max, y_min, y_max) = self.extent(pixmap);
+    let sx = (x_max - x_min) / pixmap.width() as f32;
+    let sy = (y_max - y_min) / pixmap.height() as f32;
+    match self.origin {
+      Origin::Upper => Transform::from_row(sx, 0.0, 0.0, -sy, x_min, y_max),
+      Origin::Lower => Transform::from_row(sx, 0.0, 0.0, sy, x_min, y_min),
+    }
+  }
+}
+
+impl Drawable for Image {
+  fn draw(&self, pixmap: &mut Pixmap, ts: &Transform, mask: Option<&Mask>) {
+    if self.config.is_hidden {
+      return;
+    }
+    let Some(image) = &self.pixmap else {
+      return;
+    };
+    let paint = PixmapPaint {
+      opacity: self.config.alpha.clamp(0.0, 1.0),
+      quality: match self.interpolation {
+        Interpolation::Nearest => FilterQuality::Nearest,
+        Interpolation::Bilinear => FilterQuality::Bilinear,
+      },
+      ..PixmapPaint::default()
+    };
+    let ts = ts.pre_concat(self.pixel_ts(image));
+    pixmap.draw_pixmap(0, 0, image.as_ref(), &paint, ts, mask);
+  }
+  fn bound(&self) -> Option<Bound> {
+    let (x_min, x_max, y_min, y_max) = self.extent(self.pixmap.as_ref()?);
+    Some(Bound {
+      x_min: x_min.min(x_max),
+      x_max: x_min.max(x_max),
+      y_min: y_min.min(y_max),
+      y_max: y_min.max(y_max),
+    })
+  }
+  fn name(&self) -> String {
+    self.name.clone()
+  }
+  // not used, the pixels carry their own colors
+  fn get_color(&self) -> [u8; 4] {
+    [255, 255, 255, 255]
+  }
+  fn set_color(&mut self, _color: [u8; 4]) {}
+}
+
+#[test]
+fn test_image_origin() {
+  let mut image = Image::new("test".to_string(), Config::default());
+  image.set_data(&[vec![0., 1.], vec![1., 1.]]);
+  image.set_colormap(Colormap::Gray);
+
+  // data y points up like in an axis, one data unit is 10 pixels
+  let ts = Transform::from_row(10.0, 0.0, 0.0, -10.0, 0.0, 20.0);
+  let sample = |image: &Image| {
+    let mut pixmap = Pixmap::new(20, 20).unwrap();
+    image.draw(&mut pixmap, &ts, None);
+    // the dark pixel z[0][0], looked up at the top and bottom left
+    (
+      pixmap.pixel(5, 5).unwrap().red(),
+      pixmap.pixel(5, 15).unwrap().red(),
+    )
+  };
+  assert_eq!(sample(&image), (0, 255));
+  image.set_origin(Origin::Lower);
+  assert_eq!(sample(&image), (255, 0));
+}
@@ -0,0 +1,97 @@
+use tiny_skia::{Mask, PathBuilder, Pixmap, Transform};
+
+use crate::{
+  drawable::{Bound, Bound3D, Drawable},
+  primitive::Config,
+  view3d::{self, View3D},
+};
+
+/// Curve through `(x, y, z)` points, drawn in an axis with `Projection::ThreeD`
+pub struct Line3D {
+  name: String,
+  x: Vec<f32>,
+  y: Vec<f32>,
+  z: Vec<f32>,
+  config: Config,
+}
+
+impl Line3D {
+  pub fn new(name: String, config: Config) -> Self {
+    Self {
+      name,
+      x: Vec::new(),
+      y: Vec::new(),
+      z: Vec::new(),
+      config,
+    }
+  }
+  /// Sets the points of the curve.
+  ///
+  /// # Arguments
+  ///
+  /// * `x`, `y`, `z` - The coordinates of each point, extra values of longer slices are ignored.
+  pub fn set_data(&mut self, x: &[f32], y: &[f32], z: &[f32]) {
+    let n = x.len().min(y.len()).min(z.len());
+    self.x = x[..n].to_vec();
+    self.y = y[..n].to_vec();
+    self.z = z[..n].to_vec();
+  }
+  /// Sets the points from a parameter t and functions for x, y and z.
+  pub fn set_parametric(
+    &mut self, t: &[f32], fx: impl Fn(f32) -> f32, fy: impl Fn(f32) -> f32, fz: impl Fn(f32) -> f32,
+  ) {
+    self.x = t.iter().map(|&v| fx(v)).collect();
+    self.y = t.iter().map(|&v| fy(v)).collect();
+    self.z = t.iter().map(|&v| fz(v)).collect();
+  }
+}
+
+impl Drawable for Line3D {
+  // only drawn in a 3d axis
+  fn draw(&self, _pixmap: &mut Pixmap, _ts: &Transform, _mask: Option<&Mask>) {}
+  fn draw_3d(&self, pixmap: &mut Pixmap, view: &View3D, mask: Option<&Mask>) {
+    if self.config.is_hidden || self.x.len() < 2 {
+      return;
+    }
+    let mut pb = PathBuilder::new();
+    let mut pen_down = false;
+    for ((&x, &y), &z) in self.x.iter().zip(&self.y).zip(&self.z) {
+      // gaps at non-finite points
+      if !(x.is_finite() && y.is_finite() && z.is_finite()) {
+        pen_down = false;
+        continue;
+      }
+      let p = view.map(x, y, z);
+      if pen_down {
+        pb.line_to(p.x, p.y);
+      } else {
+        pb.move_to(p.x, p.y);
+        pen_down = true;
+      }
+    }
+    if let Some(path) = pb.finish() {
+      pixmap.stroke_path(
+        &path,
+        &self.config.paint(),
+        &self.config.stroke(),
+        Transform::identity(),
+        mask,
+      );
+    }
+  }
+  fn bound(&self) -> Option<Bound> {
+    None
+  }
+  fn bound_3d(&self) -> Option<Bound3D> {
+    view3d::bound_of(&self.x, &self.y, &self.z)
+  }
+  fn name(&self) -> String {
+    self.name.clone()
+  }
+  fn get_color(&self) -> [u8; 4] {
+    self.config.color
+  }
+  fn set_color(&mut self, color: [u8; 4]) {
+    self.config.color = color;
+  }
+}
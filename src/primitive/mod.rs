@@ -1,16 +1,53 @@
+mod annotation;
 mod area;
+mod bins;
+mod boxplot;
+mod candlestick;
 mod config;
+mod contour;
 mod curve;
 mod errorbar;
 mod heatmap;
 mod histrogram;
+mod image;
+mod line3d;
+mod quiver;
+mod radar;
+mod refline;
 mod scatter;
+mod scatter3d;
 mod stair;
+mod stem;
+mod streamplot;
+mod surface;
+mod text;
+mod uncertainty;
+mod vector_field;
+mod violin;
 
+pub use crate::text_render::{HAlign, VAlign};
+pub use annotation::{Annotation, ArrowHead, ArrowStyle};
 pub use area::{Area, AreaType};
-pub use config::Config;
+pub use bins::Bins;
+pub use boxplot::{BoxPlot, Whisker};
+pub use candlestick::{CandleStyle, Candlestick};
+pub use config::{Config, DashPattern, LineStyle, Orientation};
+pub use contour::{Contour, ContourFilled};
 pub use curve::Curve;
 pub use errorbar::{ErrorBar, ErrorBarType};
-pub use histrogram::Histrogram;
+pub use histrogram::{BarMode, HistStyle, Histrogram};
+pub use image::{Image, Interpolation, Origin};
+pub use line3d::Line3D;
+pub use quiver::Quiver;
+pub use radar::Radar;
+pub use refline::{RefLine, RefSpan};
 pub use scatter::Scatter;
+pub use scatter3d::Scatter3D;
 pub use stair::{Stair, StairStyle};
+pub use stem::{Marker, Stem};
+pub use streamplot::StreamPlot;
+pub use surface::{Surface, SurfaceStyle};
+pub use text::{Coords, Text};
+pub use tiny_skia::{LineCap, LineJoin};
+pub use uncertainty::{ErrorStyle, Uncertainty};
+pub use violin::{Bandwidth, Violin, ViolinInner};
@@ -0,0 +1,193 @@
+use tiny_skia::{Color, FillRule, Mask, PathBuilder, Pixmap, Point, Transform};
+
+use super::{
+  config::Config,
+  vector_field::{VectorField, push_arrow_head},
+};
+use crate::{
+  color::{self, Colormap},
+  drawable::{Bound, Drawable},
+  text_render::{HAlign, TextRender, TextStyle, VAlign},
+};
+
+/// Arrows of a vector field, one at each grid point
+pub struct Quiver {
+  name: String,
+  field: VectorField,
+  /// data units per unit of magnitude, `None` fits the longest arrow into the grid spacing
+  scale: Option<f32>,
+  colormap: Option<Colormap>,
+  /// reference arrow `(magnitude, label)`
+  key: Option<(f32, String)>,
+  config: Config,
+}
+
+impl Quiver {
+  pub fn new(name: String, config: Config) -> Self {
+    Self {
+      name,
+      field: VectorField::default(),
+      scale: None,
+      colormap: None,
+      key: None,
+      config,
+    }
+  }
+  /// Sets the vectors on a grid.
+  ///
+  /// # Arguments
+  ///
+  /// * `x` - The ascending x coordinates of the columns.
+  /// * `y` - The ascending y coordinates of the rows.
+  /// * `u` - The x components, `u[j][i]` is the vector at `(x[i], y[j])`.
+  /// * `v` - The y components, laid out like `u`.
+  pub fn set_data(&mut self, x: &[f32], y: &[f32], u: &[Vec<f32>], v: &[Vec<f32>]) {
+    self.field.set_data(x, y, u, v);
+  }
+  /// Sets the vectors by evaluating `f(x, y) -> (u, v)` on the grid.
+  pub fn set_fn(&mut self, x: &[f32], y: &[f32], f: impl Fn(f32, f32) -> (f32, f32)) {
+    self.field.set_fn(x, y, f);
+  }
+  /// Sets the arrow length in data units per unit of magnitude.
+  ///
+  /// `None` scales the longest arrow to 90% of the grid spacing, which is the default.
+  pub fn set_scale(&mut self, scale: Option<f32>) {
+    self.scale = scale;
+  }
+  /// Colors the arrows by their magnitude, `None` uses the color of the config.
+  pub fn set_color_by_magnitude(&mut self, colormap: Option<Colormap>) {
+    self.colormap = colormap;
+  }
+  /// Shows a reference arrow of the given magnitude above the top right corner of the grid.
+  pub fn set_key(&mut self, magnitude: f32, label: &str) {
+    self.key = Some((magnitude, label.to_string()));
+  }
+  fn scale(&self) -> f32 {
+    if let Some(scale) = self.scale {
+      return scale;
+    }
+    let (_, max) = self.field.magnitude_range();
+    if max > 0.0 && max.is_finite() {
+      0.9 * self.field.min_spacing() / max
+    } else {
+      0.0
+    }
+  }
+  /// stroke a shaft and fill a head from `p` to `q`, both in pixels
+  fn draw_arrow(
+    &self, pixmap: &mut Pixmap, p: Point, q: Point, color: [u8; 4], mask: Option<&Mask>,
+  ) {
+    let dir = Point::from_xy(q.x - p.x, q.y - p.y);
+    let len = dir.x.hypot(dir.y);
+    if len < 0.5 {
+      return;
+    }
+    let head = (len * 0.4).min(4.0 + self.config.stroke_width * 2.0);
+    // end the shaft inside the head so the line cap doesn't poke out of the tip
+    let shaft_end = Point::from_xy(
+      q.x - dir.x / len * head * 0.5,
+      q.y - dir.y / len * head * 0.5,
+    );
+
+    let mut config = self.config;
+    config.color = color;
+    let paint = config.paint();
+
+    let mut pb = PathBuilder::new();
+    pb.move_to(p.x, p.y);
+    pb.line_to(shaft_end.x, shaft_end.y);
+    if let Some(path) = pb.finish() {
+      pixmap.stroke_path(&path, &paint, &config.stroke(), Transform::identity(), mask);
+    }
+    let mut pb = PathBuilder::new();
+    push_arrow_head(&mut pb, q, dir, head);
+    if let Some(path) = pb.finish() {
+      pixmap.fill_path(
+        &path,
+        &paint,
+        FillRule::Winding,
+        Transform::identity(),
+        mask,
+      );
+    }
+  }
+}
+
+impl Drawable for Quiver {
+  fn draw(&self, pixmap: &mut Pixmap, ts: &Transform, mask: Option<&Mask>) {
+    if self.config.is_hidden || !self.field.is_valid() {
+      return;
+    }
+    let scale = self.scale();
+    let (lo, hi) = self.field.magnitude_range();
+    let range = if hi > lo { hi - lo } else { 1.0 };
+
+    for (x, y, u, v) in self.field.points() {
+      if !(u.is_finite() && v.is_finite()) {
+        continue;
+      }
+      let color = match &self.colormap {
+        Some(cmap) => cmap.map((u.hypot(v) - lo) / range),
+        None => self.config.color,
+      };
+      let mut p = Point::from_xy(x, y);
+      let mut q = Point::from_xy(x + u * scale, y + v * scale);
+      ts.map_point(&mut p);
+      ts.map_point(&mut q);
+      self.draw_arrow(pixmap, p, q, color, mask);
+    }
+  }
+  fn draw_text(
+    &self, pixmap: &mut Pixmap, ts: &Transform, _axes_ts: &Transform, mask: Option<&Mask>,
+    tr: &TextRender,
+  ) {
+    let Some((magnitude, label)) = &self.key else {
+      return;
+    };
+    if self.config.is_hidden || !self.field.is_valid() {
+      return;
+    }
+    let (_, x_max, _, y_max) = self.field.extent();
+    let mut q = Point::from_xy(x_max, y_max);
+    let mut p = Point::from_xy(x_max - magnitude * self.scale(), y_max);
+    ts.map_point(&mut p);
+    ts.map_point(&mut q);
+    // lift the key above the top row of arrows
+    let lift = 16.0;
+    p.y -= lift;
+    q.y -= lift;
+    self.draw_arrow(pixmap, p, q, self.config.color, mask);
+
+    let [r, g, b, a] = color::get_fg();
+    let style = TextStyle {
+      size: 10.0,
+      color: Color::from_rgba8(r, g, b, a),
+      h_align: HAlign::Right,
+      v_align: VAlign::Center,
+      ..TextStyle::default()
+    };
+    tr.draw_styled(pixmap, label, p.x - 6.0, p.y, &style, mask);
+  }
+  fn bound(&self) -> Option<Bound> {
+    let mut bound = self.field.bound()?;
+    let scale = self.scale();
+    for (x, y, u, v) in self.field.points() {
+      if u.is_finite() && v.is_finite() {
+        bound.x_min = bound.x_min.min(x + u * scale);
+        bound.x_max = bound.x_max.max(x + u * scale);
+        bound.y_min = bound.y_min.min(y + v * scale);
+        bound.y_max = bound.y_max.max(y + v * scale);
+      }
+    }
+    Some(bound)
+  }
+  fn name(&self) -> String {
+    self.name.clone()
+  }
+  fn get_color(&self) -> [u8; 4] {
+    self.config.color
+  }
+  fn set_color(&mut self, color: [u8; 4]) {
+    self.config.color = color;
+  }
+}
@@ -0,0 +1,220 @@
+use std::f32::consts::TAU;
+
+use tiny_skia::{FillRule, Mask, PathBuilder, Pixmap, Transform};
+
+use crate::{
+  drawable::{Bound, Drawable},
+  polar::Polar,
+  primitive::Config,
+};
+
+/// Radar (spider) chart of one series, drawn in an axis with `Projection::Polar`
+/// * spoke `i` of `n` is at the angle `i * 2π / n`
+/// * without ranges the values are radii on the radial scale of the axis, fitted to every series
+/// * with ranges each value is scaled by the range of its spoke, set them on every series
+/// * add one `Radar` per series to the same axis, the spokes come from the first one
+pub struct Radar {
+  name: String,
+  values: Vec<f32>,
+  labels: Vec<String>,
+  /// `(min, max)` of each spoke, `None` uses the radial scale of the axis
+  ranges: Option<Vec<(f32, f32)>>,
+  /// alpha of the filled polygon, `None` draws only the outline
+  fill_alpha: Option<u8>,
+  config: Config,
+}
+
+impl Radar {
+  /// number of grid rings
+  const RINGS: usize = 5;
+
+  pub fn new(name: String, config: Config) -> Self {
+    Self {
+      name,
+      values: Vec::new(),
+      labels: Vec::new(),
+      ranges: None,
+      fill_alpha: Some(64),
+      config,
+    }
+  }
+  /// Sets the value of each spoke.
+  pub fn set_data(&mut self, values: &[f32]) {
+    self.values = values.to_vec();
+  }
+  /// Sets the labels of the spokes, also the number of spokes when longer than the data.
+  pub fn set_labels(&mut self, labels: &[&str]) {
+    self.labels = labels.iter().map(|l| l.to_string()).collect();
+  }
+  /// Sets the `(min, max)` of each spoke, values outside are clamped to the center or outer ring.
+  ///
+  /// Spokes past the end of `ranges` use its last range, so one range is shared by all of them.
+  /// Without ranges, or with an empty slice, the values are radii on the radial scale of the axis,
+  /// which goes from `0` to a "nice" value above the largest value of every series.
+  pub fn set_ranges(&mut self, ranges: &[(f32, f32)]) {
+    self.ranges = (!ranges.is_empty()).then(|| ranges.to_vec());
+  }
+  /// Sets the alpha of the filled polygon, `None` draws only the outline, default is `Some(64)`.
+  pub fn set_fill_alpha(&mut self, alpha: Option<u8>) {
+    self.fill_alpha = alpha;
+  }
+
+  fn spokes(&self) -> usize {
+    self.values.len().max(self.labels.len())
+  }
+  fn theta(&self, i: usize) -> f32 {
+    i as f32 * TAU / self.spokes() as f32
+  }
+  /// range of spoke `i`, `None` without ranges
+  fn range(&self, i: usize) -> Option<(f32, f32)> {
+    let ranges = self.ranges.as_ref()?;
+    ranges.get(i).or(ranges.last()).copied()
+  }
+  /// the range every spoke shares, if any
+  fn shared_range(&self) -> Option<(f32, f32)> {
+    let first = self.range(0)?;
+    (1..self.spokes())
+      .all(|i| self.range(i) == Some(first))
+      .then_some(first)
+  }
+  /// radius of spoke `i`: its value, or the value scaled into `0..1` by the range of the spoke
+  fn radius(&self, i: usize) -> f32 {
+    let v = self.values.get(i).cloned();
+    match self.range(i) {
+      Some((lo, hi)) => ((v.unwrap_or(lo) - lo) / (hi - lo).max(1e-6)).clamp(0.0, 1.0),
+      None => v.unwrap_or(0.0).max(0.0),
+    }
+  }
+}
+
+impl Drawable for Radar {
+  // only drawn in a polar axis
+  fn draw(&self, _pixmap: &mut Pixmap, _ts: &Transform, _mask: Option<&Mask>) {}
+  fn draw_polar(&self, pixmap: &mut Pixmap, polar: &Polar, mask: Option<&Mask>) {
+    if self.config.is_hidden || self.values.is_empty() {
+      return;
+    }
+    // straight edges between the spokes, not arcs
+    let mut pb = PathBuilder::new();
+    for i in 0..self.spokes() {
+      let p = polar.map(self.theta(i), self.radius(i));
+      if i == 0 {
+        pb.move_to(p.x, p.y);
+      } else {
+        pb.line_to(p.x, p.y);
+      }
+    }
+    pb.close();
+    let Some(path) = pb.finish() else {
+      return;
+    };
+    let paint = self.config.paint();
+    if let Some(alpha) = self.fill_alpha {
+      let mut fill = paint.clone();
+      let [r, g, b, _] = self.config.rgba();
+      fill.set_color_rgba8(r, g, b, alpha);
+      pixmap.fill_path(&path, &fill, FillRule::Winding, Transform::identity(), mask);
+    }
+    pixmap.stroke_path(
+      &path,
+      &paint,
+      &self.config.stroke(),
+      Transform::identity(),
+      mask,
+    );
+  }
+  // not drawn in a cartesian axis, so it doesn't change its limits
+  fn bound(&self) -> Option<Bound> {
+    None
+  }
+  /// the largest radius, the outer ring with ranges
+  fn bound_polar(&self) -> Option<Bound> {
+    if self.values.is_empty() {
+      return None;
+    }
+    let y_max = (0..self.spokes())
+      .map(|i| self.radius(i))
+      .fold(0.0, f32::max);
+    Some(Bound {
+      x_min: 0.0,
+      x_max: TAU,
+      y_min: 0.0,
+      y_max: if self.ranges.is_some() { 1.0 } else { y_max },
+    })
+  }
+  fn name(&self) -> String {
+    self.name.clone()
+  }
+  fn get_color(&self) -> [u8; 4] {
+    self.config.color
+  }
+  fn set_color(&mut self, color: [u8; 4]) {
+    self.config.color = color;
+  }
+  /// the spokes and their labels
+  fn x_labels(&self) -> Option<Vec<(f32, String)>> {
+    if self.spokes() == 0 {
+      return None;
+    }
+    Some(
+      (0..self.spokes())
+        .map(|i| {
+          (
+            self.theta(i),
+            self.labels.get(i).cloned().unwrap_or_default(),
+          )
+        })
+        .collect(),
+    )
+  }
+  /// the grid rings with ranges, labelled only when every spoke shares one range
+  ///
+  /// without ranges the rings are the radial ticks of the axis
+  fn y_labels(&self) -> Option<Vec<(f32, String)>> {
+    if self.spokes() == 0 || self.ranges.is_none() {
+      return None;
+    }
+    let shared = self.shared_range();
+    Some(
+      (1..=Self::RINGS)
+        .map(|k| {
+          let f = k as f32 / Self::RINGS as f32;
+          let label = shared
+            .map(|(lo, hi)| format!("{:.1}", lo + (hi - lo) * f))
+            .unwrap_or_default();
+          (f, label)
+        })
+        .collect(),
+    )
+  }
+}
+
+#[test]
+fn test_radar_ranges() {
+  let mut radar = Radar::new("service".to_string(), Config::default());
+  radar.set_data(&[5.0, 50.0, 0.5]);
+  radar.set_ranges(&[(0.0, 10.0), (0.0, 100.0), (0.0, 1.0)]);
+  for i in 0..3 {
+    assert!((radar.radius(i) - 0.5).abs() < 1e-6);
+  }
+  assert!(radar.shared_range().is_none());
+  assert!(radar.y_labels().unwrap().iter().all(|(_, l)| l.is_empty()));
+}
+
+#[test]
+fn test_radar_shared_scale() {
+  use crate::axis::{Axis, Projection};
+
+  let mut axis = Axis::new(0.0, 0.0, (400.0, 300.0));
+  axis.set_projection(Projection::Polar);
+  let mut small = Radar::new("a".to_string(), Config::default());
+  small.set_data(&[1.0, 2.0, 3.0]);
+  let mut large = Radar::new("b".to_string(), Config::default());
+  large.set_data(&[10.0, 20.0, 30.0]);
+  // both series are drawn on one radial scale, labelled by the rings of the axis
+  assert_eq!(small.radius(2) * 10.0, large.radius(2));
+  assert!(small.y_labels().is_none() && small.bound().is_none());
+  axis.add(Box::new(small));
+  axis.add(Box::new(large));
+  assert_eq!(axis.polar_limit(), (0.0, 30.0));
+}
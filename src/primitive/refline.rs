@@ -0,0 +1,311 @@
+use tiny_skia::{FillRule, Mask, PathBuilder, Pixmap, Point, Rect, Transform};
+
+use crate::{
+  color,
+  drawable::{Bound, Drawable},
+  primitive::{config::Config, text::Text},
+  text_render::{HAlign, TextRender, VAlign},
+};
+
+/// pixels between a label and its line or the edge of the plot area
+const LABEL_GAP: f32 = 4.0;
+const LABEL_SIZE: f32 = 10.0;
+
+/// Infinite horizontal or vertical line (axhline, axvline), e.g. a threshold
+/// * the line crosses the whole plot area whatever the limits, and is clipped to it
+/// * it doesn't change the limits unless `set_include_in_limits` is on
+/// * `config` sets the color, dash and alpha, the color is the foreground when unset
+pub struct RefLine {
+  name: String,
+  value: f32,
+  horizontal: bool,
+  label: Option<String>,
+  include_in_limits: bool,
+  config: Config,
+}
+
+impl RefLine {
+  pub fn new(name: String, mut config: Config) -> Self {
+    if config.color == [0, 0, 0, 0] {
+      config.color = color::get_fg();
+    }
+    Self {
+      name,
+      value: 0.0,
+      horizontal: true,
+      label: None,
+      include_in_limits: false,
+      config,
+    }
+  }
+  /// Places a horizontal line at `y`.
+  pub fn set_horizontal(&mut self, y: f32) {
+    (self.value, self.horizontal) = (y, true);
+  }
+  /// Places a vertical line at `x`.
+  pub fn set_vertical(&mut self, x: f32) {
+    (self.value, self.horizontal) = (x, false);
+  }
+  /// Sets a label drawn along the line, at the right end of a horizontal one and the top of a
+  /// vertical one.
+  pub fn set_label(&mut self, label: Option<&str>) {
+    self.label = label.map(|l| l.to_string());
+  }
+  /// Makes the automatic limits include the line, default is `false`.
+  pub fn set_include_in_limits(&mut self, include: bool) {
+    self.include_in_limits = include;
+  }
+}
+
+impl Drawable for RefLine {
+  fn draw(&self, pixmap: &mut Pixmap, ts: &Transform, mask: Option<&Mask>) {
+    if self.config.is_hidden {
+      return;
+    }
+    let mut p = Point::from_xy(self.value, self.value);
+    ts.map_point(&mut p);
+    // across the whole pixmap, the mask keeps the part in the plot area
+    let (w, h) = (pixmap.width() as f32, pixmap.height() as f32);
+    let mut pb = PathBuilder::new();
+    if self.horizontal {
+      pb.move_to(0.0, p.y);
+      pb.line_to(w, p.y);
+    } else {
+      pb.move_to(p.x, 0.0);
+      pb.line_to(p.x, h);
+    }
+    if let Some(path) = pb.finish() {
+      pixmap.stroke_path(
+        &path,
+        &self.config.paint(),
+        &self.config.stroke(),
+        Transform::identity(),
+        mask,
+      );
+    }
+  }
+  fn draw_text(
+    &self, pixmap: &mut Pixmap, ts: &Transform, axes_ts: &Transform, mask: Option<&Mask>,
+    tr: &TextRender,
+  ) {
+    let Some(label) = self.label.as_ref().filter(|_| !self.config.is_hidden) else {
+      return;
+    };
+    let mut p = Point::from_xy(self.value, self.value);
+    ts.map_point(&mut p);
+    let mut corner = Point::from_xy(1.0, 1.0);
+    axes_ts.map_point(&mut corner);
+    let mut text = Text::new(label.clone(), self.config);
+    text.set_font_size(LABEL_SIZE);
+    // above the right end, or left of the top end reading upward
+    text.set_align(HAlign::Right, VAlign::Bottom);
+    let anchor = if self.horizontal {
+      Point::from_xy(corner.x - LABEL_GAP, p.y - LABEL_GAP / 2.0)
+    } else {
+      text.set_rotation(90.0);
+      Point::from_xy(p.x - LABEL_GAP / 2.0, corner.y + LABEL_GAP)
+    };
+    text.draw_at(pixmap, anchor, mask, tr);
+  }
+  fn bound(&self) -> Option<Bound> {
+    if !self.include_in_limits {
+      return None;
+    }
+    // no extent along the line
+    let (lo, hi) = (f32::INFINITY, f32::NEG_INFINITY);
+    Some(if self.horizontal {
+      Bound {
+        x_min: lo,
+        x_max: hi,
+        y_min: self.value,
+        y_max: self.value,
+      }
+    } else {
+      Bound {
+        x_min: self.value,
+        x_max: self.value,
+        y_min: lo,
+        y_max: hi,
+      }
+    })
+  }
+  fn name(&self) -> String {
+    self.name.clone()
+  }
+  fn get_color(&self) -> [u8; 4] {
+    self.config.color
+  }
+  fn set_color(&mut self, color: [u8; 4]) {
+    self.config.color = color;
+  }
+}
+
+/// Horizontal or vertical band (axhspan, axvspan), e.g. an incident window
+/// * the band crosses the whole plot area whatever the limits, and is clipped to it
+/// * it doesn't change the limits unless `set_include_in_limits` is on
+/// * filled with a quarter of the alpha of `config`, the color is the foreground when unset
+pub struct RefSpan {
+  name: String,
+  range: (f32, f32),
+  horizontal: bool,
+  label: Option<String>,
+  include_in_limits: bool,
+  config: Config,
+}
+
+impl RefSpan {
+  /// fraction of the alpha of the color used to fill the band
+  const FILL_ALPHA: f32 = 0.25;
+
+  pub fn new(name: String, mut config: Config) -> Self {
+    if config.color == [0, 0, 0, 0] {
+      config.color = color::get_fg();
+    }
+    Self {
+      name,
+      range: (0.0, 0.0),
+      horizontal: true,
+      label: None,
+      include_in_limits: false,
+      config,
+    }
+  }
+  /// Places a horizontal band from `y0` to `y1`.
+  pub fn set_horizontal(&mut self, y0: f32, y1: f32) {
+    (self.range, self.horizontal) = ((y0.min(y1), y0.max(y1)), true);
+  }
+  /// Places a vertical band from `x0` to `x1`.
+  pub fn set_vertical(&mut self, x0: f32, x1: f32) {
+    (self.range, self.horizontal) = ((x0.min(x1), x0.max(x1)), false);
+  }
+  /// Sets a label drawn inside the band, at the right end of a horizontal one and the top of a
+  /// vertical one.
+  pub fn set_label(&mut self, label: Option<&str>) {
+    self.label = label.map(|l| l.to_string());
+  }
+  /// Makes the automatic limits include the band, default is `false`.
+  pub fn set_include_in_limits(&mut self, include: bool) {
+    self.include_in_limits = include;
+  }
+  /// pixels of the two edges of the band
+  fn edges(&self, ts: &Transform) -> (Point, Point) {
+    let (lo, hi) = self.range;
+    let (mut p, mut q) = (Point::from_xy(lo, lo), Point::from_xy(hi, hi));
+    ts.map_point(&mut p);
+    ts.map_point(&mut q);
+    (p, q)
+  }
+}
+
+impl Drawable for RefSpan {
+  fn draw(&self, pixmap: &mut Pixmap, ts: &Transform, mask: Option<&Mask>) {
+    if self.config.is_hidden {
+      return;
+    }
+    let (p, q) = self.edges(ts);
+    // across the whole pixmap, the mask keeps the part in the plot area
+    let (w, h) = (pixmap.width() as f32, pixmap.height() as f32);
+    let rect = if self.horizontal {
+      Rect::from_ltrb(0.0, p.y.min(q.y), w, p.y.max(q.y))
+    } else {
+      Rect::from_ltrb(p.x.min(q.x), 0.0, p.x.max(q.x), h)
+    };
+    let Some(rect) = rect else {
+      return;
+    };
+    let mut fill = self.config.paint();
+    let [r, g, b, a] = self.config.rgba();
+    fill.set_color_rgba8(r, g, b, (a as f32 * Self::FILL_ALPHA) as u8);
+    pixmap.fill_path(
+      &PathBuilder::from_rect(rect),
+      &fill,
+      FillRule::Winding,
+      Transform::identity(),
+      mask,
+    );
+  }
+  fn draw_text(
+    &self, pixmap: &mut Pixmap, ts: &Transform, axes_ts: &Transform, mask: Option<&Mask>,
+    tr: &TextRender,
+  ) {
+    let Some(label) = self.label.as_ref().filter(|_| !self.config.is_hidden) else {
+      return;
+    };
+    let (p, q) = self.edges(ts);
+    let mut corner = Point::from_xy(1.0, 1.0);
+    axes_ts.map_point(&mut corner);
+    let mut text = Text::new(label.clone(), self.config);
+    text.set_font_size(LABEL_SIZE);
+    // inside the band: at the right end of a horizontal one, under the top of a vertical one
+    let anchor = if self.horizontal {
+      text.set_align(HAlign::Right, VAlign::Center);
+      Point::from_xy(corner.x - LABEL_GAP, (p.y + q.y) / 2.0)
+    } else {
+      text.set_align(HAlign::Center, VAlign::Top);
+      Point::from_xy((p.x + q.x) / 2.0, corner.y + LABEL_GAP)
+    };
+    text.draw_at(pixmap, anchor, mask, tr);
+  }
+  fn bound(&self) -> Option<Bound> {
+    if !self.include_in_limits {
+      return None;
+    }
+    // no extent along the band
+    let (lo, hi) = (f32::INFINITY, f32::NEG_INFINITY);
+    let (min, max) = self.range;
+    Some(if self.horizontal {
+      Bound {
+        x_min: lo,
+        x_max: hi,
+        y_min: min,
+        y_max: max,
+      }
+    } else {
+      Bound {
+        x_min: min,
+        x_max: max,
+        y_min: lo,
+        y_max: hi,
+      }
+    })
+  }
+  fn name(&self) -> String {
+    self.name.clone()
+  }
+  fn get_color(&self) -> [u8; 4] {
+    self.config.color
+  }
+  fn set_color(&mut self, color: [u8; 4]) {
+    self.config.color = color;
+  }
+}
+
+#[test]
+fn test_refline_limits() {
+  use crate::{axis::Axis, primitive::Curve};
+
+  let mut axis = Axis::new(0.0, 0.0, (400.0, 300.0));
+  let mut curve = Curve::new("c".to_string(), Config::default());
+  curve.set_data(&[0.0, 10.0], &[0.0, 10.0]);
+  axis.add(Box::new(curve));
+  let mut line = RefLine::new("slo".to_string(), Config::default());
+  line.set_horizontal(100.0);
+  axis.add(Box::new(line));
+  let mut span = RefSpan::new("incident".to_string(), Config::default());
+  span.set_vertical(-50.0, -40.0);
+  axis.add(Box::new(span));
+  let b = axis.data_bound().unwrap();
+  assert_eq!((b.x_min, b.x_max, b.y_min, b.y_max), (0.0, 10.0, 0.0, 10.0));
+
+  // a line alone only bounds its own direction
+  let mut axis = Axis::new(0.0, 0.0, (400.0, 300.0));
+  let mut line = RefLine::new("slo".to_string(), Config::default());
+  line.set_horizontal(100.0);
+  line.set_include_in_limits(true);
+  axis.add(Box::new(line));
+  let b = axis.data_bound().unwrap();
+  assert_eq!(
+    (b.x_min, b.x_max, b.y_min, b.y_max),
+    (0.0, 1.0, 100.0, 100.0)
+  );
+}
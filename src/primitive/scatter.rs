@@ -1,8 +1,12 @@
-use tiny_skia::{FillRule, Paint, PathBuilder, Pixmap, Point, Transform};
+use tiny_skia::{FillRule, Mask, PathBuilder, Pixmap, Point, Transform};
 
 use crate::{
   drawable::{Bound, Drawable},
-  primitive::Config,
+  polar::Polar,
+  primitive::{
+    Config,
+    uncertainty::{ErrorStyle, Uncertainty},
+  },
 };
 
 /// Scatter
@@ -15,6 +19,7 @@ pub struct Scatter {
   y: Vec<f32>,
   value: Option<Vec<f32>>,
   forth_dim: Option<Vec<f32>>,
+  err: Uncertainty,
   config: Config,
 }
 
@@ -26,6 +31,7 @@ impl Scatter {
       y: Vec::new(),
       value: None,
       forth_dim: None,
+      err: Uncertainty::default(),
       config,
     }
   }
@@ -114,37 +120,88 @@ impl Scatter {
   pub fn set_data_norm(&mut self, y: &[f32]) {
     self.set_data_prototype(y, 0., 1.);
   }
+  /// Attaches a symmetric error to each y value.
+  ///
+  /// # Arguments
+  ///
+  /// * `err` - The error of each point, drawn from `y - err` to `y + err`.
+  pub fn set_yerr(&mut self, err: &[f32]) {
+    self.err.set_y(err);
+  }
+  /// Attaches an asymmetric error to each y value.
+  ///
+  /// # Arguments
+  ///
+  /// * `lower` - The error below each point.
+  /// * `upper` - The error above each point.
+  pub fn set_yerr_asym(&mut self, lower: &[f32], upper: &[f32]) {
+    self.err.set_y_asym(lower, upper);
+  }
+  /// Attaches a symmetric error to each x value.
+  ///
+  /// # Arguments
+  ///
+  /// * `err` - The error of each point, drawn from `x - err` to `x + err`.
+  pub fn set_xerr(&mut self, err: &[f32]) {
+    self.err.set_x(err);
+  }
+  /// Attaches an asymmetric error to each x value.
+  ///
+  /// # Arguments
+  ///
+  /// * `lower` - The error left of each point.
+  /// * `upper` - The error right of each point.
+  pub fn set_xerr_asym(&mut self, lower: &[f32], upper: &[f32]) {
+    self.err.set_x_asym(lower, upper);
+  }
+  /// Sets how the errors are drawn, default is `ErrorStyle::Bars`.
+  pub fn set_err_style(&mut self, style: ErrorStyle) {
+    self.err.set_style(style);
+  }
+  /// The x/y errors of the points, as set by the methods above.
+  pub fn err_mut(&mut self) -> &mut Uncertainty {
+    &mut self.err
+  }
+}
+
+impl Scatter {
+  const RADIUS: f32 = 5.;
+
+  /// radius of each point, scaled by `value` relative to its mean
+  fn radii(&self) -> Vec<f32> {
+    let values = match &self.value {
+      Some(values) => values.clone(),
+      None => vec![1.0; self.x.len()],
+    };
+
+    let mean = values.iter().sum::<f32>() / values.len() as f32;
+    values
+      .iter()
+      .take(self.x.len())
+      .map(|value| (value / mean).clamp(1., 6.) * Self::RADIUS)
+      .collect()
+  }
 }
 
 impl Drawable for Scatter {
-  fn draw(&self, pixmap: &mut Pixmap, ts: &Transform) {
+  fn draw(&self, pixmap: &mut Pixmap, ts: &Transform, mask: Option<&Mask>) {
     if self.x.len() != self.y.len() || self.x.is_empty() {
       return;
     }
 
-    const RADIUS: f32 = 5.;
-
-    let mut paint = Paint::default();
-    let [r, g, b, a] = self.config.color;
-    paint.anti_alias = true;
-
-    let values;
-    if self.value.is_none() {
-      values = vec![1.0; self.x.len()];
-    } else {
-      values = self.value.as_ref().unwrap().clone();
-    }
+    self
+      .err
+      .draw(pixmap, ts, &self.x, &self.y, &self.config, mask);
 
-    let mean = values.iter().sum::<f32>() / values.len() as f32;
+    let mut paint = self.config.paint();
+    let [r, g, b, a] = self.config.rgba();
 
-    for i in 0..self.x.len() {
+    for (i, radius) in self.radii().into_iter().enumerate() {
       let mut center = Point::from_xy(self.x[i], self.y[i]);
       // switch paint to for range to prepare for setting color based on forth_dim
       paint.set_color_rgba8(r, g, b, a);
       ts.map_point(&mut center);
 
-      let radius = (values[i] / mean).clamp(1., 6.) * RADIUS;
-
       // 简单圆形散点
       if let Some(circle) = PathBuilder::from_circle(center.x, center.y, radius) {
         pixmap.fill_path(
@@ -152,11 +209,31 @@ impl Drawable for Scatter {
           &paint,
           FillRule::Winding,
           Transform::identity(),
-          None,
+          mask,
         );
       }
     }
   }
+  fn draw_polar(&self, pixmap: &mut Pixmap, polar: &Polar, mask: Option<&Mask>) {
+    if self.x.len() != self.y.len() || self.x.is_empty() {
+      return;
+    }
+    let paint = self.config.paint();
+    let mut pb = PathBuilder::new();
+    for (i, radius) in self.radii().into_iter().enumerate() {
+      let center = polar.map(self.x[i], self.y[i]);
+      pb.push_circle(center.x, center.y, radius);
+    }
+    if let Some(path) = pb.finish() {
+      pixmap.fill_path(
+        &path,
+        &paint,
+        FillRule::Winding,
+        Transform::identity(),
+        mask,
+      );
+    }
+  }
 
   fn bound(&self) -> Option<Bound> {
     if self.x.is_empty() || self.y.is_empty() || self.x.len() != self.y.len() {
@@ -175,13 +252,20 @@ impl Drawable for Scatter {
       y_min = y_min.min(yv);
       y_max = y_max.max(yv);
     }
+    let mut b = Bound {
+      x_min,
+      x_max,
+      y_min,
+      y_max,
+    };
+    self.err.extend_bound(&mut b, &self.x, &self.y);
 
     // 重点 2：对称补偿
     Some(Bound {
-      x_min: if x_min == 0. { 0. } else { x_min - padding },
-      x_max: x_max + padding,
-      y_min: if y_min == 0. { 0. } else { y_min - padding },
-      y_max: y_max + padding,
+      x_min: if b.x_min == 0. { 0. } else { b.x_min - padding },
+      x_max: b.x_max + padding,
+      y_min: if b.y_min == 0. { 0. } else { b.y_min - padding },
+      y_max: b.y_max + padding,
     })
   }
   fn name(&self) -> String {
@@ -0,0 +1,115 @@
+use tiny_skia::{FillRule, Mask, PathBuilder, Pixmap, Stroke, Transform};
+
+use crate::{
+  color,
+  drawable::{Bound, Bound3D, Drawable},
+  primitive::Config,
+  view3d::{self, View3D},
+};
+
+/// Scatter of `(x, y, z)` points, drawn in an axis with `Projection::ThreeD`
+/// * points are drawn from back to front and fade toward the background with depth
+pub struct Scatter3D {
+  name: String,
+  x: Vec<f32>,
+  y: Vec<f32>,
+  z: Vec<f32>,
+  /// radius of the points in pixels
+  size: f32,
+  config: Config,
+}
+
+impl Scatter3D {
+  /// share of the background mixed into the farthest points
+  const DEPTH_FADE: f32 = 0.45;
+
+  pub fn new(name: String, config: Config) -> Self {
+    Self {
+      name,
+      x: Vec::new(),
+      y: Vec::new(),
+      z: Vec::new(),
+      size: 5.0,
+      config,
+    }
+  }
+  /// Sets the points.
+  ///
+  /// # Arguments
+  ///
+  /// * `x`, `y`, `z` - The coordinates of each point, extra values of longer slices are ignored.
+  pub fn set_data(&mut self, x: &[f32], y: &[f32], z: &[f32]) {
+    let n = x.len().min(y.len()).min(z.len());
+    self.x = x[..n].to_vec();
+    self.y = y[..n].to_vec();
+    self.z = z[..n].to_vec();
+  }
+  /// Sets the radius of the points in pixels, default is `5.0`.
+  pub fn set_size(&mut self, radius: f32) {
+    self.size = radius.max(0.5);
+  }
+}
+
+impl Drawable for Scatter3D {
+  // only drawn in a 3d axis
+  fn draw(&self, _pixmap: &mut Pixmap, _ts: &Transform, _mask: Option<&Mask>) {}
+  fn draw_3d(&self, pixmap: &mut Pixmap, view: &View3D, mask: Option<&Mask>) {
+    if self.config.is_hidden || self.x.is_empty() {
+      return;
+    }
+    let mut points: Vec<(f32, tiny_skia::Point)> = self
+      .x
+      .iter()
+      .zip(&self.y)
+      .zip(&self.z)
+      .filter(|((x, y), z)| x.is_finite() && y.is_finite() && z.is_finite())
+      .map(|((&x, &y), &z)| (view.depth(x, y, z), view.map(x, y, z)))
+      .collect();
+    // painter's algorithm: farthest first
+    points.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+    let [r, g, b, a] = self.config.rgba();
+    let bg = color::get_bg();
+    let mut paint = self.config.paint();
+    let mut outline = self.config.paint();
+    outline.set_color_rgba8(bg[0], bg[1], bg[2], a);
+    let stroke = Stroke {
+      width: 1.0,
+      ..Stroke::default()
+    };
+    // depth of a corner of the cube is at most √3
+    let max_depth = 3_f32.sqrt();
+    for (depth, p) in points {
+      let fade = (max_depth - depth) / (2.0 * max_depth) * Self::DEPTH_FADE;
+      let mix = |c: u8, bg: u8| (c as f32 + (bg as f32 - c as f32) * fade.clamp(0.0, 1.0)) as u8;
+      paint.set_color_rgba8(mix(r, bg[0]), mix(g, bg[1]), mix(b, bg[2]), a);
+      let mut pb = PathBuilder::new();
+      pb.push_circle(p.x, p.y, self.size);
+      if let Some(path) = pb.finish() {
+        pixmap.fill_path(
+          &path,
+          &paint,
+          FillRule::Winding,
+          Transform::identity(),
+          mask,
+        );
+        pixmap.stroke_path(&path, &outline, &stroke, Transform::identity(), mask);
+      }
+    }
+  }
+  fn bound(&self) -> Option<Bound> {
+    None
+  }
+  fn bound_3d(&self) -> Option<Bound3D> {
+    view3d::bound_of(&self.x, &self.y, &self.z)
+  }
+  fn name(&self) -> String {
+    self.name.clone()
+  }
+  fn get_color(&self) -> [u8; 4] {
+    self.config.color
+  }
+  fn set_color(&mut self, color: [u8; 4]) {
+    self.config.color = color;
+  }
+}
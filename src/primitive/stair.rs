@@ -3,7 +3,7 @@ use crate::{
   primitive::config::Config,
 };
 
-use tiny_skia::{Paint, PathBuilder, Pixmap, Point, Stroke, Transform};
+use tiny_skia::{Mask, PathBuilder, Pixmap, Point, Transform};
 pub enum StairStyle {
   TraceX,
   TraceY,
@@ -56,7 +56,7 @@ impl Stair {
 }
 
 impl Drawable for Stair {
-  fn draw(&self, pixmap: &mut Pixmap, ts: &Transform) {
+  fn draw(&self, pixmap: &mut Pixmap, ts: &Transform, mask: Option<&Mask>) {
     if self.x.len() < 2 {
       return;
     }
@@ -111,18 +111,11 @@ impl Drawable for Stair {
     }
 
     if let Some(path) = pb.finish() {
-      let mut paint = Paint::default();
-      let [r, g, b, a] = self.config.color;
-      paint.set_color_rgba8(r, g, b, a);
-      paint.anti_alias = true;
-
-      let stroke = Stroke {
-        width: self.config.stroke_width, // 这里直接用像素线宽，不用除以任何东西
-        ..Default::default()
-      };
+      let paint = self.config.paint();
+      let stroke = self.config.stroke();
 
       // 既然点已经 map 过了，这里必须传 identity()
-      pixmap.stroke_path(&path, &paint, &stroke, Transform::identity(), None);
+      pixmap.stroke_path(&path, &paint, &stroke, Transform::identity(), mask);
     }
   }
 
@@ -0,0 +1,209 @@
+use tiny_skia::{FillRule, Mask, PathBuilder, Pixmap, Point, Rect, Stroke, Transform};
+
+use crate::{
+  drawable::{Bound, Drawable},
+  primitive::config::Config,
+};
+
+/// Shape drawn at the head of each stem
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum Marker {
+  #[default]
+  Circle,
+  Square,
+  Diamond,
+  /// no head, only the line
+  None,
+}
+
+/// Stem plot of a discrete sequence
+/// * each sample is a line from the baseline to `y` with a marker head
+pub struct Stem {
+  name: String,
+  x: Vec<f32>,
+  y: Vec<f32>,
+  baseline: f32,
+  show_baseline: bool,
+  marker: Marker,
+  /// radius of the marker head in pixels
+  marker_size: f32,
+  config: Config,
+}
+
+impl Stem {
+  pub fn new(name: String, config: Config) -> Self {
+    Self {
+      name,
+      x: Vec::new(),
+      y: Vec::new(),
+      baseline: 0.0,
+      show_baseline: true,
+      marker: Marker::default(),
+      marker_size: 4.0,
+      config,
+    }
+  }
+  /// Sets the samples.
+  ///
+  /// # Arguments
+  ///
+  /// * `x` - A slice of x-coordinates.
+  /// * `y` - A slice of sample values, as long as `x`.
+  pub fn set_data(&mut self, x: &[f32], y: &[f32]) {
+    let n = x.len().min(y.len());
+    self.x = x[..n].to_vec();
+    self.y = y[..n].to_vec();
+  }
+  /// Sets the samples with normalized x coordinates (0, 1, 2, ...).
+  ///
+  /// # Arguments
+  ///
+  /// * `y` - A slice of sample values.
+  pub fn set_data_norm(&mut self, y: &[f32]) {
+    self.x = (0..y.len()).map(|i| i as f32).collect();
+    self.y = y.to_vec();
+  }
+  /// Sets the value the stems start from, default is `0.0`.
+  pub fn set_baseline(&mut self, baseline: f32) {
+    self.baseline = baseline;
+  }
+  /// Shows or hides the horizontal line at the baseline.
+  pub fn set_show_baseline(&mut self, show: bool) {
+    self.show_baseline = show;
+  }
+  /// Sets the marker head and its radius in pixels.
+  ///
+  /// # Arguments
+  ///
+  /// * `marker` - The shape of the head, `Marker::None` draws only the stems.
+  /// * `size` - The radius of the head in pixels, default is `4.0`.
+  pub fn set_marker(&mut self, marker: Marker, size: f32) {
+    self.marker = marker;
+    self.marker_size = size;
+  }
+  fn push_marker(&self, pb: &mut PathBuilder, p: Point) {
+    let s = self.marker_size;
+    match self.marker {
+      Marker::Circle => pb.push_circle(p.x, p.y, s),
+      Marker::Square => {
+        if let Some(rect) = Rect::from_xywh(p.x - s, p.y - s, 2.0 * s, 2.0 * s) {
+          pb.push_rect(rect);
+        }
+      }
+      Marker::Diamond => {
+        pb.move_to(p.x, p.y - s * 1.3);
+        pb.line_to(p.x + s, p.y);
+        pb.line_to(p.x, p.y + s * 1.3);
+        pb.line_to(p.x - s, p.y);
+        pb.close();
+      }
+      Marker::None => {}
+    }
+  }
+}
+
+impl Drawable for Stem {
+  fn draw(&self, pixmap: &mut Pixmap, ts: &Transform, mask: Option<&Mask>) {
+    if self.config.is_hidden || self.x.is_empty() {
+      return;
+    }
+    let map = |x: f32, y: f32| {
+      let mut p = Point::from_xy(x, y);
+      ts.map_point(&mut p);
+      p
+    };
+    let paint = self.config.paint();
+
+    if self.show_baseline {
+      let x_min = self.x.iter().cloned().fold(f32::INFINITY, f32::min);
+      let x_max = self.x.iter().cloned().fold(f32::NEG_INFINITY, f32::max);
+      let (l, r) = (map(x_min, self.baseline), map(x_max, self.baseline));
+      let mut pb = PathBuilder::new();
+      pb.move_to(l.x - self.marker_size, l.y);
+      pb.line_to(r.x + self.marker_size, r.y);
+      if let Some(path) = pb.finish() {
+        let stroke = Stroke {
+          width: 1.0,
+          ..Stroke::default()
+        };
+        pixmap.stroke_path(&path, &paint, &stroke, Transform::identity(), mask);
+      }
+    }
+
+    let mut stems = PathBuilder::new();
+    let mut heads = PathBuilder::new();
+    for (&x, &y) in self.x.iter().zip(self.y.iter()) {
+      if !y.is_finite() {
+        continue;
+      }
+      let (base, head) = (map(x, self.baseline), map(x, y));
+      stems.move_to(base.x, base.y);
+      stems.line_to(head.x, head.y);
+      self.push_marker(&mut heads, head);
+    }
+    if let Some(path) = stems.finish() {
+      pixmap.stroke_path(
+        &path,
+        &paint,
+        &self.config.stroke(),
+        Transform::identity(),
+        mask,
+      );
+    }
+    if let Some(path) = heads.finish() {
+      pixmap.fill_path(
+        &path,
+        &paint,
+        FillRule::Winding,
+        Transform::identity(),
+        mask,
+      );
+    }
+  }
+  fn bound(&self) -> Option<Bound> {
+    if self.x.is_empty() {
+      return None;
+    }
+    let x_min = self.x.iter().fold(f32::INFINITY, |a, &b| a.min(b));
+    let x_max = self.x.iter().fold(f32::NEG_INFINITY, |a, &b| a.max(b));
+    let y_min = self.y.iter().fold(self.baseline, |a, &b| a.min(b));
+    let y_max = self.y.iter().fold(self.baseline, |a, &b| a.max(b));
+
+    Some(Bound {
+      x_min,
+      x_max,
+      y_min,
+      y_max,
+    })
+  }
+  fn name(&self) -> String {
+    self.name.clone()
+  }
+  fn get_color(&self) -> [u8; 4] {
+    self.config.color
+  }
+  fn set_color(&mut self, color: [u8; 4]) {
+    self.config.color = color;
+  }
+}
+
+#[test]
+fn test_stem_bound() {
+  let mut stem = Stem::new("s".to_string(), Config::default());
+  assert!(stem.bound().is_none());
+  stem.set_data_norm(&[3.0, 5.0, 4.0]);
+  assert_eq!(stem.x, [0.0, 1.0, 2.0]);
+
+  // the stems grow from the baseline, which stays in the bound
+  let b = stem.bound().unwrap();
+  assert_eq!((b.x_min, b.x_max, b.y_min, b.y_max), (0.0, 2.0, 0.0, 5.0));
+  stem.set_baseline(10.0);
+  let b = stem.bound().unwrap();
+  assert_eq!((b.y_min, b.y_max), (3.0, 10.0));
+  stem.set_baseline(-1.0);
+  assert_eq!(stem.bound().unwrap().y_min, -1.0);
+
+  // extra values without an x are dropped
+  stem.set_data(&[1.0, 2.0], &[1.0, 2.0, 9.0]);
+  assert_eq!(stem.bound().unwrap().y_max, 2.0);
+}
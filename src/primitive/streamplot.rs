@@ -0,0 +1,264 @@
+use tiny_skia::{FillRule, Mask, PathBuilder, Pixmap, Point, Transform};
+
+use super::{
+  config::Config,
+  vector_field::{VectorField, push_arrow_head},
+};
+use crate::{
+  color::Colormap,
+  drawable::{Bound, Drawable},
+};
+
+/// Streamlines integrated through a vector field
+/// * the grid is split into `30 * density` cells per side, each cell holds at most one line
+pub struct StreamPlot {
+  name: String,
+  field: VectorField,
+  density: f32,
+  colormap: Option<Colormap>,
+  config: Config,
+}
+
+/// a streamline, points as `(x, y, magnitude)` in data units
+type Line = Vec<(f32, f32, f32)>;
+
+impl StreamPlot {
+  /// cells per side at density 1
+  const CELLS: f32 = 30.0;
+  /// integration step in cells
+  const STEP: f32 = 0.3;
+
+  pub fn new(name: String, config: Config) -> Self {
+    Self {
+      name,
+      field: VectorField::default(),
+      density: 1.0,
+      colormap: None,
+      config,
+    }
+  }
+  /// Sets the vectors on a grid.
+  ///
+  /// # Arguments
+  ///
+  /// * `x` - The ascending x coordinates of the columns.
+  /// * `y` - The ascending y coordinates of the rows.
+  /// * `u` - The x components, `u[j][i]` is the vector at `(x[i], y[j])`.
+  /// * `v` - The y components, laid out like `u`.
+  pub fn set_data(&mut self, x: &[f32], y: &[f32], u: &[Vec<f32>], v: &[Vec<f32>]) {
+    self.field.set_data(x, y, u, v);
+  }
+  /// Sets the vectors by evaluating `f(x, y) -> (u, v)` on the grid.
+  pub fn set_fn(&mut self, x: &[f32], y: &[f32], f: impl Fn(f32, f32) -> (f32, f32)) {
+    self.field.set_fn(x, y, f);
+  }
+  /// Sets how closely the lines are packed, default is `1.0`.
+  pub fn set_density(&mut self, density: f32) {
+    self.density = density.max(0.1);
+  }
+  /// Colors the lines by the local magnitude, `None` uses the color of the config.
+  pub fn set_color_by_magnitude(&mut self, colormap: Option<Colormap>) {
+    self.colormap = colormap;
+  }
+
+  /// integrate the streamlines, seeded cell by cell
+  fn lines(&self) -> Vec<Line> {
+    let (x0, x1, y0, y1) = self.field.extent();
+    let (w, h) = (x1 - x0, y1 - y0);
+    if w <= 0.0 || h <= 0.0 {
+      return Vec::new();
+    }
+    let n = (Self::CELLS * self.density).round().max(2.0) as usize;
+    let mut occupied = vec![false; n * n];
+    // cells of the line being integrated, cleared after each seed through its `own` list
+    let mut owned = vec![false; n * n];
+
+    // the integration runs in the unit square, so both directions share the cell size
+    let cell = |p: (f32, f32)| -> Option<usize> {
+      if !(0.0..=1.0).contains(&p.0) || !(0.0..=1.0).contains(&p.1) {
+        return None;
+      }
+      let i = ((p.0 * n as f32) as usize).min(n - 1);
+      let j = ((p.1 * n as f32) as usize).min(n - 1);
+      Some(j * n + i)
+    };
+    let direction = |p: (f32, f32)| -> Option<(f32, f32)> {
+      let (u, v) = self.field.sample(x0 + p.0 * w, y0 + p.1 * h)?;
+      let (du, dv) = (u / w, v / h);
+      let norm = du.hypot(dv);
+      (norm > 1e-12).then(|| (du / norm, dv / norm))
+    };
+    let ds = Self::STEP / n as f32;
+    let max_steps = (4.0 / ds) as usize;
+
+    // midpoint (rk2) steps from `start` until the line leaves the grid, stalls or meets a line
+    let integrate = |start: (f32, f32),
+                     sign: f32,
+                     occupied: &[bool],
+                     owned: &mut [bool],
+                     own: &mut Vec<usize>| {
+      let mut points = Vec::new();
+      let mut p = start;
+      let mut current = cell(p);
+      for _ in 0..max_steps {
+        let Some(d1) = direction(p) else { break };
+        let mid = (p.0 + sign * d1.0 * ds * 0.5, p.1 + sign * d1.1 * ds * 0.5);
+        let Some(d2) = direction(mid) else { break };
+        let next = (p.0 + sign * d2.0 * ds, p.1 + sign * d2.1 * ds);
+        let Some(c) = cell(next) else { break };
+        if Some(c) != current {
+          if occupied[c] || owned[c] {
+            break;
+          }
+          owned[c] = true;
+          own.push(c);
+          current = Some(c);
+        }
+        points.push(next);
+        p = next;
+      }
+      points
+    };
+
+    let mut lines = Vec::new();
+    for seed in 0..n * n {
+      if occupied[seed] {
+        continue;
+      }
+      let start = (
+        ((seed % n) as f32 + 0.5) / n as f32,
+        ((seed / n) as f32 + 0.5) / n as f32,
+      );
+      let mut own = vec![seed];
+      owned[seed] = true;
+      let backward = integrate(start, -1.0, &occupied, &mut owned, &mut own);
+      let forward = integrate(start, 1.0, &occupied, &mut owned, &mut own);
+
+      // too short lines leave their cells free for other seeds
+      let steps = backward.len() + forward.len();
+      let keep = (steps as f32) * ds >= 2.0 / n as f32;
+      for &c in &own {
+        owned[c] = false;
+        occupied[c] |= keep;
+      }
+      if !keep {
+        continue;
+      }
+      let line = backward
+        .into_iter()
+        .rev()
+        .chain(std::iter::once(start))
+        .chain(forward)
+        .map(|(ux, uy)| {
+          let (x, y) = (x0 + ux * w, y0 + uy * h);
+          let mag = self
+            .field
+            .sample(x, y)
+            .map(|(u, v)| u.hypot(v))
+            .unwrap_or(0.0);
+          (x, y, mag)
+        })
+        .collect();
+      lines.push(line);
+    }
+    lines
+  }
+}
+
+impl Drawable for StreamPlot {
+  fn draw(&self, pixmap: &mut Pixmap, ts: &Transform, mask: Option<&Mask>) {
+    if self.config.is_hidden || !self.field.is_valid() {
+      return;
+    }
+    let map = |x: f32, y: f32| {
+      let mut p = Point::from_xy(x, y);
+      ts.map_point(&mut p);
+      p
+    };
+    let (lo, hi) = self.field.magnitude_range();
+    let range = if hi > lo { hi - lo } else { 1.0 };
+    let stroke = self.config.stroke();
+    let mut config = self.config;
+    let head = 4.0 + self.config.stroke_width * 2.0;
+
+    for line in self.lines() {
+      match &self.colormap {
+        Some(cmap) => {
+          // one segment per step, each with the color of its start
+          for w in line.windows(2) {
+            let (p, q) = (map(w[0].0, w[0].1), map(w[1].0, w[1].1));
+            let mut pb = PathBuilder::new();
+            pb.move_to(p.x, p.y);
+            pb.line_to(q.x, q.y);
+            config.color = cmap.map((w[0].2 - lo) / range);
+            if let Some(path) = pb.finish() {
+              pixmap.stroke_path(&path, &config.paint(), &stroke, Transform::identity(), mask);
+            }
+          }
+        }
+        None => {
+          let mut pb = PathBuilder::new();
+          for (i, &(x, y, _)) in line.iter().enumerate() {
+            let p = map(x, y);
+            if i == 0 {
+              pb.move_to(p.x, p.y);
+            } else {
+              pb.line_to(p.x, p.y);
+            }
+          }
+          if let Some(path) = pb.finish() {
+            pixmap.stroke_path(&path, &config.paint(), &stroke, Transform::identity(), mask);
+          }
+        }
+      }
+
+      // arrow head in the middle of the line, pointing downstream
+      let mid = line.len() / 2;
+      if mid + 1 < line.len() {
+        let (p, q) = (
+          map(line[mid].0, line[mid].1),
+          map(line[mid + 1].0, line[mid + 1].1),
+        );
+        if let Some(cmap) = &self.colormap {
+          config.color = cmap.map((line[mid].2 - lo) / range);
+        }
+        let mut pb = PathBuilder::new();
+        push_arrow_head(&mut pb, q, Point::from_xy(q.x - p.x, q.y - p.y), head);
+        if let Some(path) = pb.finish() {
+          pixmap.fill_path(
+            &path,
+            &config.paint(),
+            FillRule::Winding,
+            Transform::identity(),
+            mask,
+          );
+        }
+      }
+    }
+  }
+  fn bound(&self) -> Option<Bound> {
+    self.field.bound()
+  }
+  fn name(&self) -> String {
+    self.name.clone()
+  }
+  fn get_color(&self) -> [u8; 4] {
+    self.config.color
+  }
+  fn set_color(&mut self, color: [u8; 4]) {
+    self.config.color = color;
+  }
+}
+
+#[test]
+fn test_uniform_stream() {
+  let mut stream = StreamPlot::new("flow".to_string(), Config::default());
+  stream.set_fn(&[0., 1., 2.], &[0., 1.], |_, _| (1.0, 0.0));
+  let lines = stream.lines();
+  // a uniform flow gives one horizontal line per row of cells, left to right
+  assert_eq!(lines.len(), StreamPlot::CELLS as usize);
+  for line in &lines {
+    assert!(line.iter().all(|p| (p.1 - line[0].1).abs() < 1e-5));
+    assert!(line.windows(2).all(|w| w[1].0 > w[0].0));
+  }
+}
@@ -0,0 +1,251 @@
+use tiny_skia::{FillRule, Mask, PathBuilder, Pixmap, Point, Stroke, Transform};
+
+use crate::{
+  color::Colormap,
+  drawable::{Bound, Bound3D, Drawable},
+  primitive::Config,
+  view3d::{self, View3D},
+};
+
+/// How a `Surface` is drawn
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum SurfaceStyle {
+  /// the grid lines only
+  Wireframe,
+  /// filled faces lit from the camera, drawn from back to front
+  #[default]
+  Shaded,
+}
+
+/// Surface `z = f(x, y)` over a rectilinear grid, drawn in an axis with `Projection::ThreeD`
+pub struct Surface {
+  name: String,
+  x: Vec<f32>,
+  y: Vec<f32>,
+  /// `z[j][i]` is the value at `(x[i], y[j])`
+  z: Vec<Vec<f32>>,
+  style: SurfaceStyle,
+  /// color the faces by their height instead of the single color
+  colormap: Option<Colormap>,
+  config: Config,
+}
+
+impl Surface {
+  pub fn new(name: String, config: Config) -> Self {
+    Self {
+      name,
+      x: Vec::new(),
+      y: Vec::new(),
+      z: Vec::new(),
+      style: SurfaceStyle::default(),
+      colormap: None,
+      config,
+    }
+  }
+  /// Sets the grid values.
+  ///
+  /// # Arguments
+  ///
+  /// * `x` - The ascending x coordinates of the columns.
+  /// * `y` - The ascending y coordinates of the rows.
+  /// * `z` - The values, `z[j][i]` at `(x[i], y[j])`.
+  pub fn set_data(&mut self, x: &[f32], y: &[f32], z: &[Vec<f32>]) {
+    self.x = x.to_vec();
+    self.y = y.to_vec();
+    self.z = z
+      .iter()
+      .take(y.len())
+      .map(|row| row.iter().take(x.len()).cloned().collect())
+      .collect();
+  }
+  /// Sets the grid values from a function of `(x, y)`.
+  pub fn set_fn(&mut self, x: &[f32], y: &[f32], f: impl Fn(f32, f32) -> f32) {
+    self.x = x.to_vec();
+    self.y = y.to_vec();
+    self.z = y
+      .iter()
+      .map(|&yv| x.iter().map(|&xv| f(xv, yv)).collect())
+      .collect();
+  }
+  /// Sets how the surface is drawn, default is `SurfaceStyle::Shaded`.
+  pub fn set_style(&mut self, style: SurfaceStyle) {
+    self.style = style;
+  }
+  /// Colors the surface by height through `colormap`, `None` uses the color of the config.
+  pub fn set_colormap(&mut self, colormap: Option<Colormap>) {
+    self.colormap = colormap;
+  }
+
+  fn is_valid(&self) -> bool {
+    self.x.len() >= 2
+      && self.y.len() >= 2
+      && self.z.len() == self.y.len()
+      && self.z.iter().all(|row| row.len() == self.x.len())
+  }
+  fn z_range(&self) -> (f32, f32) {
+    min_max(self.z.iter().flatten())
+  }
+  fn draw_wireframe(&self, pixmap: &mut Pixmap, view: &View3D, mask: Option<&Mask>) {
+    let mut pb = PathBuilder::new();
+    let mut push_line = |points: &mut dyn Iterator<Item = Point>| {
+      if let Some(p) = points.next() {
+        pb.move_to(p.x, p.y);
+      }
+      for p in points {
+        pb.line_to(p.x, p.y);
+      }
+    };
+    for (j, &y) in self.y.iter().enumerate() {
+      push_line(&mut (0..self.x.len()).map(|i| view.map(self.x[i], y, self.z[j][i])));
+    }
+    for (i, &x) in self.x.iter().enumerate() {
+      push_line(&mut (0..self.y.len()).map(|j| view.map(x, self.y[j], self.z[j][i])));
+    }
+    if let Some(path) = pb.finish() {
+      pixmap.stroke_path(
+        &path,
+        &self.config.paint(),
+        &self.config.stroke(),
+        Transform::identity(),
+        mask,
+      );
+    }
+  }
+  fn draw_shaded(&self, pixmap: &mut Pixmap, view: &View3D, mask: Option<&Mask>) {
+    let (z_min, z_max) = self.z_range();
+    let z_span = (z_max - z_min).max(1e-6);
+    let [r, g, b, a] = self.config.rgba();
+
+    // (depth, corners in data, mean height) of every finite cell
+    let mut faces = Vec::new();
+    for j in 0..self.y.len() - 1 {
+      for i in 0..self.x.len() - 1 {
+        let corners = [(i, j), (i + 1, j), (i + 1, j + 1), (i, j + 1)]
+          .map(|(i, j)| [self.x[i], self.y[j], self.z[j][i]]);
+        if corners.iter().flatten().any(|v| !v.is_finite()) {
+          continue;
+        }
+        let mean = corners.iter().fold([0.0; 3], |m, c| {
+          [m[0] + c[0] / 4.0, m[1] + c[1] / 4.0, m[2] + c[2] / 4.0]
+        });
+        faces.push((view.depth(mean[0], mean[1], mean[2]), corners, mean[2]));
+      }
+    }
+    // painter's algorithm: farthest first
+    faces.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+    let mut paint = self.config.paint();
+    // the outline closes the hairline gaps between neighbouring faces
+    let seam = Stroke {
+      width: 1.0,
+      ..Stroke::default()
+    };
+    for (_, corners, height) in faces {
+      let n = corners.map(|c| view.normalize(c));
+      let diag1 = [n[2][0] - n[0][0], n[2][1] - n[0][1], n[2][2] - n[0][2]];
+      let diag2 = [n[3][0] - n[1][0], n[3][1] - n[1][1], n[3][2] - n[1][2]];
+      let light = view.shade(view3d::cross(diag1, diag2));
+      let [r, g, b, _] = match self.colormap {
+        Some(cmap) => cmap.map((height - z_min) / z_span),
+        None => [r, g, b, a],
+      };
+      let lit = |c: u8| (c as f32 * light) as u8;
+      paint.set_color_rgba8(lit(r), lit(g), lit(b), a);
+
+      let mut pb = PathBuilder::new();
+      let p = view.map(corners[0][0], corners[0][1], corners[0][2]);
+      pb.move_to(p.x, p.y);
+      for c in &corners[1..] {
+        let p = view.map(c[0], c[1], c[2]);
+        pb.line_to(p.x, p.y);
+      }
+      pb.close();
+      if let Some(path) = pb.finish() {
+        pixmap.fill_path(
+          &path,
+          &paint,
+          FillRule::Winding,
+          Transform::identity(),
+          mask,
+        );
+        pixmap.stroke_path(&path, &paint, &seam, Transform::identity(), mask);
+      }
+    }
+  }
+}
+
+impl Drawable for Surface {
+  // only drawn in a 3d axis
+  fn draw(&self, _pixmap: &mut Pixmap, _ts: &Transform, _mask: Option<&Mask>) {}
+  fn draw_3d(&self, pixmap: &mut Pixmap, view: &View3D, mask: Option<&Mask>) {
+    if self.config.is_hidden || !self.is_valid() {
+      return;
+    }
+    match self.style {
+      SurfaceStyle::Wireframe => self.draw_wireframe(pixmap, view, mask),
+      SurfaceStyle::Shaded => self.draw_shaded(pixmap, view, mask),
+    }
+  }
+  fn bound(&self) -> Option<Bound> {
+    None
+  }
+  fn bound_3d(&self) -> Option<Bound3D> {
+    if !self.is_valid() {
+      return None;
+    }
+    // the grid may be given in descending order
+    let (x_min, x_max) = min_max(&self.x);
+    let (y_min, y_max) = min_max(&self.y);
+    let (z_min, z_max) = self.z_range();
+    let bound = Bound3D {
+      x_min,
+      x_max,
+      y_min,
+      y_max,
+      z_min,
+      z_max,
+    };
+    [x_min, y_min, z_min]
+      .iter()
+      .all(|v| v.is_finite())
+      .then_some(bound)
+  }
+  fn name(&self) -> String {
+    self.name.clone()
+  }
+  fn get_color(&self) -> [u8; 4] {
+    self.config.color
+  }
+  fn set_color(&mut self, color: [u8; 4]) {
+    self.config.color = color;
+  }
+}
+
+/// `(min, max)` of the finite values, infinite when there is none
+fn min_max<'a>(values: impl IntoIterator<Item = &'a f32>) -> (f32, f32) {
+  values
+    .into_iter()
+    .filter(|v| v.is_finite())
+    .fold((f32::INFINITY, f32::NEG_INFINITY), |(lo, hi), &v| {
+      (lo.min(v), hi.max(v))
+    })
+}
+
+#[test]
+fn test_surface_bound() {
+  let mut surface = Surface::new("s".to_string(), Config::default());
+  surface.set_data(
+    &[2.0, 1.0, 0.0],
+    &[5.0, f32::NAN, -5.0],
+    &[
+      vec![1.0, 2.0, 3.0],
+      vec![4.0, f32::NAN, 6.0],
+      vec![-1.0, 0.0, 1.0],
+    ],
+  );
+  let b = surface.bound_3d().unwrap();
+  assert_eq!(
+    (b.x_min, b.x_max, b.y_min, b.y_max, b.z_min, b.z_max),
+    (0.0, 2.0, -5.0, 5.0, -1.0, 6.0)
+  );
+}
@@ -0,0 +1,224 @@
+use tiny_skia::{Color, FillRule, Mask, Paint, PathBuilder, Pixmap, Point, Rect, Transform};
+
+use crate::{
+  color,
+  drawable::{Bound, Drawable},
+  primitive::config::Config,
+  text_render::{HAlign, TextRender, TextStyle, VAlign},
+};
+
+/// Coordinates a `Text` or an `Annotation` is placed in
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum Coords {
+  /// data coordinates of the axis
+  #[default]
+  Data,
+  /// fractions of the plot area, `(0, 0)` is bottom left and `(1, 1)` top right
+  Axes,
+  /// pixels of the figure, `(0, 0)` is top left
+  Pixel,
+}
+
+impl Coords {
+  /// pixel position of `(x, y)`
+  pub(crate) fn map(&self, x: f32, y: f32, ts: &Transform, axes_ts: &Transform) -> Point {
+    let mut p = Point::from_xy(x, y);
+    match self {
+      Coords::Data => ts.map_point(&mut p),
+      Coords::Axes => axes_ts.map_point(&mut p),
+      Coords::Pixel => {}
+    }
+    p
+  }
+}
+
+/// Text placed at a point of the axis
+/// * the position is in data, axes-fraction or pixel coordinates, see `Coords`
+/// * the text is drawn above every other drawable and doesn't change the limits of the axis
+/// * `config.color` is the color of the text, the foreground color when unset
+pub struct Text {
+  text: String,
+  x: f32,
+  y: f32,
+  coords: Coords,
+  h_align: HAlign,
+  v_align: VAlign,
+  /// degrees, counter-clockwise around the position
+  rotation: f32,
+  font_size: f32,
+  /// color of the box behind the text, `None` draws no box
+  background: Option<[u8; 4]>,
+  clip: bool,
+  config: Config,
+}
+
+impl Text {
+  /// pixels between the text and the edge of its box
+  const PAD: f32 = 3.0;
+
+  pub fn new(text: String, mut config: Config) -> Self {
+    if config.color == [0, 0, 0, 0] {
+      config.color = color::get_fg();
+    }
+    Self {
+      text,
+      x: 0.0,
+      y: 0.0,
+      coords: Coords::default(),
+      h_align: HAlign::default(),
+      v_align: VAlign::Bottom,
+      rotation: 0.0,
+      font_size: 12.0,
+      background: None,
+      clip: false,
+      config,
+    }
+  }
+  /// Sets the position of the text.
+  ///
+  /// # Arguments
+  ///
+  /// * `x`, `y` - The position, the side of the text given by `set_align` is placed there.
+  /// * `coords` - The coordinates of `x` and `y`, default is `Coords::Data`.
+  pub fn set_position(&mut self, x: f32, y: f32, coords: Coords) {
+    (self.x, self.y, self.coords) = (x, y, coords);
+  }
+  /// Sets which side of the text is at the position, default is the bottom left corner.
+  pub fn set_align(&mut self, h_align: HAlign, v_align: VAlign) {
+    self.h_align = h_align;
+    self.v_align = v_align;
+  }
+  /// Sets the rotation in degrees, counter-clockwise around the position.
+  pub fn set_rotation(&mut self, degrees: f32) {
+    self.rotation = degrees;
+  }
+  /// Sets the font size in pixels, default is `12.0`.
+  pub fn set_font_size(&mut self, size: f32) {
+    self.font_size = size.max(1.0);
+  }
+  /// Sets the color of a box drawn behind the text, `None` draws no box.
+  pub fn set_background(&mut self, color: Option<[u8; 4]>) {
+    self.background = color;
+  }
+  /// Clips the text to the plot area, default is `false`.
+  pub fn set_clip(&mut self, clip: bool) {
+    self.clip = clip;
+  }
+
+  /// pixel position of the text
+  pub(crate) fn anchor(&self, ts: &Transform, axes_ts: &Transform) -> Point {
+    self.coords.map(self.x, self.y, ts, axes_ts)
+  }
+  fn style(&self) -> TextStyle {
+    let [r, g, b, a] = self.config.rgba();
+    TextStyle {
+      size: self.font_size,
+      color: Color::from_rgba8(r, g, b, a),
+      h_align: self.h_align,
+      v_align: self.v_align,
+      rotation: self.rotation,
+    }
+  }
+  /// the box behind the text, in the layout of `TextRender::transform`
+  fn padded_box(&self, tr: &TextRender) -> Option<Rect> {
+    let (w, h) = tr.measure(&self.text, self.font_size);
+    Rect::from_xywh(
+      -Self::PAD,
+      -Self::PAD,
+      w + 2.0 * Self::PAD,
+      h + 2.0 * Self::PAD,
+    )
+  }
+  /// pixels covered by the box of the text at `anchor`, rotation included
+  pub(crate) fn rect(&self, anchor: Point, tr: &TextRender) -> Option<Rect> {
+    let ts = tr.transform(&self.text, anchor.x, anchor.y, &self.style());
+    let r = self.padded_box(tr)?;
+    let mut corners = [
+      Point::from_xy(r.left(), r.top()),
+      Point::from_xy(r.right(), r.top()),
+      Point::from_xy(r.right(), r.bottom()),
+      Point::from_xy(r.left(), r.bottom()),
+    ];
+    ts.map_points(&mut corners);
+    Rect::from_points(&corners)
+  }
+  /// draw the box and the text with its position at `anchor`
+  pub(crate) fn draw_at(
+    &self, pixmap: &mut Pixmap, anchor: Point, mask: Option<&Mask>, tr: &TextRender,
+  ) {
+    if self.config.is_hidden || self.text.is_empty() {
+      return;
+    }
+    // whole pixels keep the upright box edges sharp
+    let (x, y) = (anchor.x.round(), anchor.y.round());
+    let style = self.style();
+    if let (Some([r, g, b, a]), Some(rect)) = (self.background, self.padded_box(tr)) {
+      let mut bg = Paint::default();
+      bg.set_color_rgba8(r, g, b, a);
+      bg.anti_alias = true;
+      let ts = tr.transform(&self.text, x, y, &style);
+      pixmap.fill_path(
+        &PathBuilder::from_rect(rect),
+        &bg,
+        FillRule::Winding,
+        ts,
+        mask,
+      );
+    }
+    tr.draw_styled(pixmap, &self.text, x, y, &style, mask);
+  }
+}
+
+impl Drawable for Text {
+  // drawn over the other drawables in `draw_text`
+  fn draw(&self, _pixmap: &mut Pixmap, _ts: &Transform, _mask: Option<&Mask>) {}
+  fn draw_text(
+    &self, pixmap: &mut Pixmap, ts: &Transform, axes_ts: &Transform, mask: Option<&Mask>,
+    tr: &TextRender,
+  ) {
+    self.draw_at(pixmap, self.anchor(ts, axes_ts), mask, tr);
+  }
+  fn clip(&self) -> bool {
+    self.clip
+  }
+  // placed by the user, the limits come from the data
+  fn bound(&self) -> Option<Bound> {
+    None
+  }
+  fn name(&self) -> String {
+    self.text.clone()
+  }
+  fn get_color(&self) -> [u8; 4] {
+    self.config.color
+  }
+  fn set_color(&mut self, color: [u8; 4]) {
+    self.config.color = color;
+  }
+}
+
+#[test]
+fn test_text_align() {
+  let tr = TextRender::new();
+  let mut text = Text::new("label".to_string(), Config::default());
+  let (w, h) = tr.measure("label", 12.0);
+  let pad = Text::PAD;
+  // bottom left by default
+  let r = text.rect(Point::from_xy(100.0, 100.0), &tr).unwrap();
+  assert!((r.left() - (100.0 - pad)).abs() < 1e-3 && (r.bottom() - (100.0 + pad)).abs() < 1e-3);
+  text.set_align(HAlign::Center, VAlign::Center);
+  let r = text.rect(Point::from_xy(100.0, 100.0), &tr).unwrap();
+  assert!((r.width() - (w + 2.0 * pad)).abs() < 1e-3);
+  assert!((r.top() - (100.0 - h / 2.0 - pad)).abs() < 1e-3);
+  // turned upright, the box swaps its sides
+  text.set_rotation(90.0);
+  let r = text.rect(Point::from_xy(100.0, 100.0), &tr).unwrap();
+  assert!((r.height() - (w + 2.0 * pad)).abs() < 1e-3);
+
+  // axes fractions follow the plot area whatever the data
+  let axes_ts = Transform::from_translate(50.0, 250.0).pre_scale(300.0, -200.0);
+  text.set_position(0.5, 1.0, Coords::Axes);
+  assert_eq!(
+    text.anchor(&Transform::identity(), &axes_ts),
+    Point::from_xy(200.0, 50.0)
+  );
+}
@@ -0,0 +1,211 @@
+use tiny_skia::{FillRule, Mask, PathBuilder, Pixmap, Point, Transform};
+
+use crate::{drawable::Bound, primitive::Config};
+
+/// How the per-point uncertainty of a `Curve` or `Scatter` is drawn
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum ErrorStyle {
+  /// whiskers with caps at each point
+  #[default]
+  Bars,
+  /// translucent band around the y values, x errors are still drawn as bars
+  Band,
+}
+
+/// lower and upper error of each point, both positive
+#[derive(Debug, Default)]
+struct Extent {
+  lower: Vec<f32>,
+  upper: Vec<f32>,
+}
+impl Extent {
+  fn get(&self, i: usize) -> (f32, f32) {
+    (
+      self.lower.get(i).cloned().unwrap_or(0.0).abs(),
+      self.upper.get(i).cloned().unwrap_or(0.0).abs(),
+    )
+  }
+}
+
+/// Optional x/y errors attached to the points of a `Curve` or `Scatter`, see their `err_mut`
+#[derive(Debug, Default)]
+pub struct Uncertainty {
+  x: Option<Extent>,
+  y: Option<Extent>,
+  style: ErrorStyle,
+}
+
+impl Uncertainty {
+  const CAP: f32 = 4.0;
+
+  /// Attaches a symmetric error to each y value.
+  ///
+  /// # Arguments
+  ///
+  /// * `err` - The error of each point, drawn from `y - err` to `y + err`.
+  pub fn set_y(&mut self, err: &[f32]) {
+    self.set_y_asym(err, err);
+  }
+  /// Attaches an asymmetric error to each y value.
+  ///
+  /// # Arguments
+  ///
+  /// * `lower` - The error below each point.
+  /// * `upper` - The error above each point.
+  pub fn set_y_asym(&mut self, lower: &[f32], upper: &[f32]) {
+    self.y = Some(Extent {
+      lower: lower.to_vec(),
+      upper: upper.to_vec(),
+    });
+  }
+  /// Attaches a symmetric error to each x value.
+  ///
+  /// # Arguments
+  ///
+  /// * `err` - The error of each point, drawn from `x - err` to `x + err`.
+  pub fn set_x(&mut self, err: &[f32]) {
+    self.set_x_asym(err, err);
+  }
+  /// Attaches an asymmetric error to each x value.
+  ///
+  /// # Arguments
+  ///
+  /// * `lower` - The error left of each point.
+  /// * `upper` - The error right of each point.
+  pub fn set_x_asym(&mut self, lower: &[f32], upper: &[f32]) {
+    self.x = Some(Extent {
+      lower: lower.to_vec(),
+      upper: upper.to_vec(),
+    });
+  }
+  /// Sets how the errors are drawn, default is `ErrorStyle::Bars`.
+  pub fn set_style(&mut self, style: ErrorStyle) {
+    self.style = style;
+  }
+
+  /// grow `bound` so it contains every error extent
+  pub(crate) fn extend_bound(&self, bound: &mut Bound, x: &[f32], y: &[f32]) {
+    for (i, (&xv, &yv)) in x.iter().zip(y.iter()).enumerate() {
+      if let Some(e) = &self.x {
+        let (lo, hi) = e.get(i);
+        bound.x_min = bound.x_min.min(xv - lo);
+        bound.x_max = bound.x_max.max(xv + hi);
+      }
+      if let Some(e) = &self.y {
+        let (lo, hi) = e.get(i);
+        bound.y_min = bound.y_min.min(yv - lo);
+        bound.y_max = bound.y_max.max(yv + hi);
+      }
+    }
+  }
+
+  /// outline of the y band in data coordinates: the upper edge forward, the lower one backward
+  fn band(&self, x: &[f32], y: &[f32]) -> Option<Vec<(f32, f32)>> {
+    let (ErrorStyle::Band, Some(e)) = (self.style, &self.y) else {
+      return None;
+    };
+    let n = x.len().min(y.len());
+    let upper = (0..n).map(|i| (x[i], y[i] + e.get(i).1));
+    let lower = (0..n).rev().map(|i| (x[i], y[i] - e.get(i).0));
+    Some(upper.chain(lower).collect())
+  }
+
+  pub(crate) fn draw(
+    &self, pixmap: &mut Pixmap, ts: &Transform, x: &[f32], y: &[f32], config: &Config,
+    mask: Option<&Mask>,
+  ) {
+    let n = x.len().min(y.len());
+    if n == 0 || (self.x.is_none() && self.y.is_none()) {
+      return;
+    }
+    let map = |x: f32, y: f32| {
+      let mut p = Point::from_xy(x, y);
+      ts.map_point(&mut p);
+      p
+    };
+    let paint = config.paint();
+
+    if let Some(band) = self.band(x, y) {
+      let mut pb = PathBuilder::new();
+      for (i, &(x, y)) in band.iter().enumerate() {
+        let p = map(x, y);
+        if i == 0 {
+          pb.move_to(p.x, p.y);
+        } else {
+          pb.line_to(p.x, p.y);
+        }
+      }
+      pb.close();
+      if let Some(path) = pb.finish() {
+        let mut fill = paint.clone();
+        let [r, g, b, a] = config.rgba();
+        fill.set_color_rgba8(r, g, b, a / 3);
+        pixmap.fill_path(&path, &fill, FillRule::Winding, Transform::identity(), mask);
+      }
+    }
+
+    let mut pb = PathBuilder::new();
+    for i in 0..n {
+      if let (ErrorStyle::Bars, Some(e)) = (self.style, &self.y) {
+        let (lo, hi) = e.get(i);
+        let (p1, p2) = (map(x[i], y[i] - lo), map(x[i], y[i] + hi));
+        pb.move_to(p1.x, p1.y);
+        pb.line_to(p2.x, p2.y);
+        for p in [p1, p2] {
+          pb.move_to(p.x - Self::CAP, p.y);
+          pb.line_to(p.x + Self::CAP, p.y);
+        }
+      }
+      if let Some(e) = &self.x {
+        let (lo, hi) = e.get(i);
+        let (p1, p2) = (map(x[i] - lo, y[i]), map(x[i] + hi, y[i]));
+        pb.move_to(p1.x, p1.y);
+        pb.line_to(p2.x, p2.y);
+        for p in [p1, p2] {
+          pb.move_to(p.x, p.y - Self::CAP);
+          pb.line_to(p.x, p.y + Self::CAP);
+        }
+      }
+    }
+    if let Some(path) = pb.finish() {
+      let mut stroke = config.stroke();
+      stroke.width = (config.stroke_width * 0.75).max(1.0);
+      stroke.dash = None;
+      pixmap.stroke_path(&path, &paint, &stroke, Transform::identity(), mask);
+    }
+  }
+}
+
+#[test]
+fn test_uncertainty() {
+  let (x, y) = ([0.0, 1.0, 2.0], [1.0, 5.0, 2.0]);
+  let mut err = Uncertainty::default();
+  err.set_y_asym(&[0.5, 1.0, 0.5], &[1.0, 2.0, -3.0]);
+  err.set_x(&[0.25]);
+  let mut bound = Bound {
+    x_min: 0.0,
+    x_max: 2.0,
+    y_min: 1.0,
+    y_max: 5.0,
+  };
+  // errors are positive, the missing x errors of the last points are 0
+  err.extend_bound(&mut bound, &x, &y);
+  assert_eq!(
+    (bound.x_min, bound.x_max, bound.y_min, bound.y_max),
+    (-0.25, 2.0, 0.5, 7.0)
+  );
+
+  assert!(err.band(&x, &y).is_none());
+  err.set_style(ErrorStyle::Band);
+  assert_eq!(
+    err.band(&x, &y).unwrap(),
+    [
+      (0.0, 2.0),
+      (1.0, 7.0),
+      (2.0, 5.0),
+      (2.0, 1.5),
+      (1.0, 4.0),
+      (0.0, 0.5)
+    ]
+  );
+}
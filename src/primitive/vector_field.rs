@@ -0,0 +1,142 @@
+use tiny_skia::{PathBuilder, Point};
+
+use crate::drawable::Bound;
+
+/// 2-D vector field sampled on a rectilinear grid, shared by `Quiver` and `StreamPlot`
+#[derive(Debug, Default)]
+pub(crate) struct VectorField {
+  x: Vec<f32>,
+  y: Vec<f32>,
+  /// `u[j][i]` and `v[j][i]` are the components at `(x[i], y[j])`
+  u: Vec<Vec<f32>>,
+  v: Vec<Vec<f32>>,
+}
+
+impl VectorField {
+  pub(crate) fn set_data(&mut self, x: &[f32], y: &[f32], u: &[Vec<f32>], v: &[Vec<f32>]) {
+    self.x = x.to_vec();
+    self.y = y.to_vec();
+    self.u = u.to_vec();
+    self.v = v.to_vec();
+  }
+  pub(crate) fn set_fn(&mut self, x: &[f32], y: &[f32], f: impl Fn(f32, f32) -> (f32, f32)) {
+    self.x = x.to_vec();
+    self.y = y.to_vec();
+    let (u, v) = y
+      .iter()
+      .map(|&yv| x.iter().map(|&xv| f(xv, yv)).unzip())
+      .unzip();
+    self.u = u;
+    self.v = v;
+  }
+  pub(crate) fn is_valid(&self) -> bool {
+    let rows_ok =
+      |m: &Vec<Vec<f32>>| m.len() == self.y.len() && m.iter().all(|row| row.len() == self.x.len());
+    !self.x.is_empty() && !self.y.is_empty() && rows_ok(&self.u) && rows_ok(&self.v)
+  }
+  /// every grid point as `(x, y, u, v)`
+  pub(crate) fn points(&self) -> impl Iterator<Item = (f32, f32, f32, f32)> + '_ {
+    self.y.iter().enumerate().flat_map(move |(j, &yv)| {
+      self
+        .x
+        .iter()
+        .enumerate()
+        .map(move |(i, &xv)| (xv, yv, self.u[j][i], self.v[j][i]))
+    })
+  }
+  /// smallest and largest finite magnitude
+  pub(crate) fn magnitude_range(&self) -> (f32, f32) {
+    self
+      .points()
+      .map(|(_, _, u, v)| u.hypot(v))
+      .filter(|m| m.is_finite())
+      .fold((f32::INFINITY, f32::NEG_INFINITY), |(lo, hi), m| {
+        (lo.min(m), hi.max(m))
+      })
+  }
+  /// smallest distance between neighbouring grid lines
+  pub(crate) fn min_spacing(&self) -> f32 {
+    let min_diff = |v: &[f32]| {
+      v.windows(2)
+        .map(|w| (w[1] - w[0]).abs())
+        .filter(|d| *d > 0.0)
+        .fold(f32::INFINITY, f32::min)
+    };
+    let spacing = min_diff(&self.x).min(min_diff(&self.y));
+    if spacing.is_finite() { spacing } else { 1.0 }
+  }
+  /// `(x_min, x_max, y_min, y_max)` of the grid
+  pub(crate) fn extent(&self) -> (f32, f32, f32, f32) {
+    let fold = |v: &[f32]| {
+      v.iter()
+        .fold((f32::INFINITY, f32::NEG_INFINITY), |(lo, hi), &x| {
+          (lo.min(x), hi.max(x))
+        })
+    };
+    let (x_min, x_max) = fold(&self.x);
+    let (y_min, y_max) = fold(&self.y);
+    (x_min, x_max, y_min, y_max)
+  }
+  pub(crate) fn bound(&self) -> Option<Bound> {
+    if !self.is_valid() {
+      return None;
+    }
+    let (x_min, x_max, y_min, y_max) = self.extent();
+    Some(Bound {
+      x_min,
+      x_max,
+      y_min,
+      y_max,
+    })
+  }
+  /// bilinear interpolation of the field at `(px, py)`, `None` outside of the grid
+  pub(crate) fn sample(&self, px: f32, py: f32) -> Option<(f32, f32)> {
+    let (i, tx) = locate(&self.x, px)?;
+    let (j, ty) = locate(&self.y, py)?;
+    let lerp = |m: &Vec<Vec<f32>>| {
+      let (i1, j1) = ((i + 1).min(self.x.len() - 1), (j + 1).min(self.y.len() - 1));
+      let bottom = m[j][i] + (m[j][i1] - m[j][i]) * tx;
+      let top = m[j1][i] + (m[j1][i1] - m[j1][i]) * tx;
+      bottom + (top - bottom) * ty
+    };
+    let (u, v) = (lerp(&self.u), lerp(&self.v));
+    (u.is_finite() && v.is_finite()).then_some((u, v))
+  }
+}
+
+/// index of the grid interval containing `p` and the fraction inside it
+fn locate(axis: &[f32], p: f32) -> Option<(usize, f32)> {
+  let (first, last) = (*axis.first()?, *axis.last()?);
+  if !(first..=last).contains(&p) {
+    return None;
+  }
+  if axis.len() == 1 {
+    return Some((0, 0.0));
+  }
+  let i = axis
+    .partition_point(|&v| v <= p)
+    .saturating_sub(1)
+    .min(axis.len() - 2);
+  let span = axis[i + 1] - axis[i];
+  let t = if span > 0.0 {
+    (p - axis[i]) / span
+  } else {
+    0.0
+  };
+  Some((i, t.clamp(0.0, 1.0)))
+}
+
+/// add a triangular arrow head with its tip at `tip`, pointing along `dir` (pixels)
+pub(crate) fn push_arrow_head(pb: &mut PathBuilder, tip: Point, dir: Point, len: f32) {
+  let norm = dir.x.hypot(dir.y);
+  if norm <= 0.0 || len <= 0.0 {
+    return;
+  }
+  let (dx, dy) = (dir.x / norm, dir.y / norm);
+  let (bx, by) = (tip.x - dx * len, tip.y - dy * len);
+  let half_w = len * 0.5;
+  pb.move_to(tip.x, tip.y);
+  pb.line_to(bx - dy * half_w, by + dx * half_w);
+  pb.line_to(bx + dy * half_w, by - dx * half_w);
+  pb.close();
+}
@@ -0,0 +1,364 @@
+use std::f32::consts::PI;
+
+use tiny_skia::{FillRule, Mask, Paint, PathBuilder, Pixmap, Point, Stroke, Transform};
+
+use crate::{
+  color,
+  drawable::{Bound, Drawable},
+  primitive::{
+    Config, LineStyle,
+    histrogram::{GROUP_WIDTH_RATIO, slot_offset},
+  },
+  utils,
+};
+
+/// Bandwidth of the gaussian kernel
+#[derive(Debug, Clone, Copy, Default)]
+pub enum Bandwidth {
+  /// `σ * n^(-1/5)`
+  #[default]
+  Scott,
+  /// `0.9 * min(σ, IQR / 1.34) * n^(-1/5)`
+  Silverman,
+  /// user given bandwidth in data units
+  Fixed(f32),
+}
+impl Bandwidth {
+  fn value(&self, sorted: &[f32]) -> f32 {
+    let n = sorted.len() as f32;
+    let mean = sorted.iter().sum::<f32>() / n;
+    let std = (sorted.iter().map(|v| (v - mean).powi(2)).sum::<f32>() / (n - 1.0).max(1.0)).sqrt();
+    let bw = match self {
+      Bandwidth::Scott => std * n.powf(-0.2),
+      Bandwidth::Silverman => {
+        let iqr = utils::quantile(sorted, 0.75) - utils::quantile(sorted, 0.25);
+        let spread = if iqr > 0.0 { std.min(iqr / 1.34) } else { std };
+        0.9 * spread * n.powf(-0.2)
+      }
+      Bandwidth::Fixed(bw) => *bw,
+    };
+    // constant samples still get a visible shape
+    if bw > 0.0 { bw } else { 1e-3 }
+  }
+}
+
+/// What is drawn inside each violin
+#[derive(Debug, Clone, Copy, Default)]
+pub enum ViolinInner {
+  None,
+  /// a thin box from q1 to q3 with whiskers to min/max and a median dot
+  #[default]
+  Box,
+  /// dashed lines at the quartiles and a solid line at the median
+  Quartiles,
+  /// every sample as a small dot on the center line
+  Points,
+}
+
+/// gaussian kernel density of `sorted` evaluated at `grid`
+fn kde(sorted: &[f32], bw: f32, grid: &[f32]) -> Vec<f32> {
+  let norm = 1.0 / (sorted.len() as f32 * bw * (2.0 * PI).sqrt());
+  grid
+    .iter()
+    .map(|&x| {
+      sorted
+        .iter()
+        .map(|&s| (-0.5 * ((x - s) / bw).powi(2)).exp())
+        .sum::<f32>()
+        * norm
+    })
+    .collect()
+}
+
+struct Series {
+  /// sorted samples per slot, `NaN` removed
+  groups: Vec<Vec<f32>>,
+  config: Config,
+}
+
+/// Violin plot, the mirrored kernel density of each group of samples
+/// * slot `i` is centered at `i + 1`, several series share a slot side by side like `Histrogram`
+pub struct Violin {
+  name: String,
+  labels: Vec<String>,
+  series: Vec<Series>,
+
+  bandwidth: Bandwidth,
+  inner: ViolinInner,
+
+  color_index: usize,
+}
+
+impl Violin {
+  const RESOLUTION: usize = 100;
+
+  pub fn new(name: String) -> Self {
+    Self {
+      name,
+      labels: Vec::new(),
+      series: Vec::new(),
+      bandwidth: Bandwidth::default(),
+      inner: ViolinInner::default(),
+      color_index: 0,
+    }
+  }
+  fn gen_config(&mut self) -> Config {
+    let index = self.color_index;
+    let color = color::get_color(index);
+    self.color_index = (index + 1) & 7;
+
+    Config {
+      color,
+      ..Config::default()
+    }
+  }
+  /// Sets the labels of the slots shown on the x axis.
+  pub fn set_labels(&mut self, labels: &[&str]) {
+    self.labels = labels.iter().map(|l| l.to_string()).collect();
+  }
+  /// Adds a data series, one violin per slot.
+  ///
+  /// Automatically assigns a color to the new series.
+  ///
+  /// # Arguments
+  ///
+  /// * `groups` - The raw samples of each slot, `NaN` values are ignored.
+  pub fn add_series(&mut self, groups: &[&[f32]]) {
+    let config = self.gen_config();
+    let groups = groups
+      .iter()
+      .map(|g| {
+        let mut g: Vec<f32> = g.iter().cloned().filter(|v| !v.is_nan()).collect();
+        g.sort_by(|a, b| a.total_cmp(b));
+        g
+      })
+      .collect();
+    self.series.push(Series { groups, config });
+  }
+  /// Sets the bandwidth of the kernel density estimation, default is `Bandwidth::Scott`.
+  pub fn set_bandwidth(&mut self, bandwidth: Bandwidth) {
+    self.bandwidth = bandwidth;
+  }
+  /// Sets what is drawn inside each violin, default is `ViolinInner::Box`.
+  pub fn set_inner(&mut self, inner: ViolinInner) {
+    self.inner = inner;
+  }
+  fn num_slots(&self) -> usize {
+    self
+      .series
+      .iter()
+      .map(|s| s.groups.len())
+      .max()
+      .unwrap_or(0)
+      .max(self.labels.len())
+  }
+}
+
+// implement for draw
+impl Violin {
+  fn draw_one(
+    &self, pixmap: &mut Pixmap, ts: &Transform, (center, half_w): (f32, f32), sorted: &[f32],
+    config: &Config, mask: Option<&Mask>,
+  ) {
+    let (v_min, v_max) = (sorted[0], sorted[sorted.len() - 1]);
+    let bw = self.bandwidth.value(sorted);
+    let grid = if v_max > v_min {
+      utils::linspace(v_min, v_max, Self::RESOLUTION)
+    } else {
+      utils::linspace(v_min - 2.0 * bw, v_max + 2.0 * bw, Self::RESOLUTION)
+    };
+    let density = kde(sorted, bw, &grid);
+    let peak = density.iter().cloned().fold(0.0, f32::max);
+    if peak <= 0.0 {
+      return;
+    }
+    // half width in data units at value `v`
+    let width_at = |v: f32| kde(sorted, bw, &[v])[0] / peak * half_w;
+    let map = |x: f32, y: f32| {
+      let mut p = Point::from_xy(x, y);
+      ts.map_point(&mut p);
+      p
+    };
+
+    let mut pb = PathBuilder::new();
+    for (i, (&v, &d)) in grid.iter().zip(density.iter()).enumerate() {
+      let p = map(center + d / peak * half_w, v);
+      if i == 0 {
+        pb.move_to(p.x, p.y);
+      } else {
+        pb.line_to(p.x, p.y);
+      }
+    }
+    for (&v, &d) in grid.iter().zip(density.iter()).rev() {
+      let p = map(center - d / peak * half_w, v);
+      pb.line_to(p.x, p.y);
+    }
+    pb.close();
+
+    let paint = config.paint();
+    if let Some(path) = pb.finish() {
+      let mut fill = paint.clone();
+      let [r, g, b, a] = config.rgba();
+      fill.set_color_rgba8(r, g, b, a / 2);
+      pixmap.fill_path(&path, &fill, FillRule::Winding, Transform::identity(), mask);
+      pixmap.stroke_path(&path, &paint, &config.stroke(), Transform::identity(), mask);
+    }
+
+    let [r, g, b, a] = color::get_fg();
+    let mut fg = Paint::default();
+    fg.set_color_rgba8(r, g, b, a);
+    fg.anti_alias = true;
+    let q1 = utils::quantile(sorted, 0.25);
+    let median = utils::quantile(sorted, 0.5);
+    let q3 = utils::quantile(sorted, 0.75);
+
+    match self.inner {
+      ViolinInner::None => {}
+      ViolinInner::Box => {
+        let mut pb = PathBuilder::new();
+        let (lo, hi) = (map(center, v_min), map(center, v_max));
+        pb.move_to(lo.x, lo.y);
+        pb.line_to(hi.x, hi.y);
+        let thin = Stroke {
+          width: 1.0,
+          ..Stroke::default()
+        };
+        if let Some(path) = pb.finish() {
+          pixmap.stroke_path(&path, &fg, &thin, Transform::identity(), mask);
+        }
+        let mut pb = PathBuilder::new();
+        let (lo, hi) = (map(center, q1), map(center, q3));
+        pb.move_to(lo.x, lo.y);
+        pb.line_to(hi.x, hi.y);
+        let thick = Stroke {
+          width: 5.0,
+          ..Stroke::default()
+        };
+        if let Some(path) = pb.finish() {
+          pixmap.stroke_path(&path, &fg, &thick, Transform::identity(), mask);
+        }
+        let m = map(center, median);
+        if let Some(circle) = PathBuilder::from_circle(m.x, m.y, 2.5) {
+          let [r, g, b, a] = color::get_bg();
+          let mut dot = Paint::default();
+          dot.set_color_rgba8(r, g, b, a);
+          dot.anti_alias = true;
+          pixmap.fill_path(
+            &circle,
+            &dot,
+            FillRule::Winding,
+            Transform::identity(),
+            mask,
+          );
+        }
+      }
+      ViolinInner::Quartiles => {
+        for (v, style) in [
+          (q1, LineStyle::Dashed),
+          (median, LineStyle::Solid),
+          (q3, LineStyle::Dashed),
+        ] {
+          let w = width_at(v);
+          let (l, r) = (map(center - w, v), map(center + w, v));
+          let mut pb = PathBuilder::new();
+          pb.move_to(l.x, l.y);
+          pb.line_to(r.x, r.y);
+          let stroke = Stroke {
+            width: 1.0,
+            dash: style.dash(1.0),
+            ..Stroke::default()
+          };
+          if let Some(path) = pb.finish() {
+            pixmap.stroke_path(&path, &fg, &stroke, Transform::identity(), mask);
+          }
+        }
+      }
+      ViolinInner::Points => {
+        let mut pb = PathBuilder::new();
+        for &v in sorted {
+          let p = map(center, v);
+          pb.push_circle(p.x, p.y, 1.5);
+        }
+        if let Some(path) = pb.finish() {
+          pixmap.fill_path(&path, &fg, FillRule::Winding, Transform::identity(), mask);
+        }
+      }
+    }
+  }
+}
+
+impl Drawable for Violin {
+  fn draw(&self, pixmap: &mut Pixmap, ts: &Transform, mask: Option<&Mask>) {
+    let num_series = self.series.len();
+    for (s_idx, series) in self.series.iter().enumerate() {
+      if series.config.is_hidden {
+        continue;
+      }
+      let (offset, width) = slot_offset(s_idx, num_series, 1.0, GROUP_WIDTH_RATIO);
+      for (slot, sorted) in series.groups.iter().enumerate() {
+        if sorted.is_empty() {
+          continue;
+        }
+        let center = (slot + 1) as f32 + offset;
+        self.draw_one(
+          pixmap,
+          ts,
+          (center, width * 0.5),
+          sorted,
+          &series.config,
+          mask,
+        );
+      }
+    }
+  }
+  fn bound(&self) -> Option<Bound> {
+    let mut y_min = f32::INFINITY;
+    let mut y_max = f32::NEG_INFINITY;
+    for series in self.series.iter().filter(|s| !s.config.is_hidden) {
+      for g in series.groups.iter().filter(|g| !g.is_empty()) {
+        y_min = y_min.min(g[0]);
+        y_max = y_max.max(g[g.len() - 1]);
+      }
+    }
+    if y_min > y_max {
+      return None;
+    }
+    Some(Bound {
+      x_min: 0.5,
+      x_max: self.num_slots() as f32 + 0.5,
+      y_min,
+      y_max,
+    })
+  }
+  fn name(&self) -> String {
+    self.name.clone()
+  }
+  // not used, manage color in self
+  fn get_color(&self) -> [u8; 4] {
+    [255, 255, 255, 255]
+  }
+  fn set_color(&mut self, _color: [u8; 4]) {}
+  fn x_labels(&self) -> Option<Vec<(f32, String)>> {
+    if self.labels.is_empty() {
+      return None;
+    }
+    Some(
+      self
+        .labels
+        .iter()
+        .enumerate()
+        .map(|(i, l)| ((i + 1) as f32, l.clone()))
+        .collect(),
+    )
+  }
+}
+
+#[test]
+fn test_kde_integrates_to_one() {
+  let sorted = [1., 2., 2.5, 3., 4.];
+  let bw = Bandwidth::Scott.value(&sorted);
+  let grid = utils::linspace(-5., 10., 1501);
+  let step = grid[1] - grid[0];
+  let area: f32 = kde(&sorted, bw, &grid).iter().sum::<f32>() * step;
+  assert!((area - 1.0).abs() < 1e-3);
+}
@@ -1,19 +1,91 @@
+use std::{
+  collections::{HashMap, HashSet},
+  sync::Mutex,
+};
+
 use ab_glyph::Font;
-use ab_glyph::{FontVec, ScaleFont};
-use fontdb::{Database, Family, Query};
+use ab_glyph::{FontArc, FontRef, FontVec, GlyphId, OutlineCurve, PxScale, ScaleFont};
+use fontdb::{Database, FaceInfo, Family, ID, Query, Style, Weight};
+use tiny_skia::{
+  Color, FillRule, Mask, Paint, PathBuilder, Pixmap, PremultipliedColorU8, Rect, Transform,
+};
+
+use crate::color;
 
+/// Draws text with a chain of fonts, each character comes from the first font having it:
+/// the font of `load_font`, the fallbacks of `set_fallbacks`, then any system font.
 pub struct TextRender {
-  font: FontVec,
+  font: FontArc,
+  fallbacks: Vec<FontArc>,
   db: Database,
+  /// system fonts found for the characters missing from the chain, filled while drawing
+  system: Mutex<SystemFonts>,
+}
+
+/// sorted, merged ranges of the code points a font has
+type Coverage = Vec<(u32, u32)>;
+
+#[derive(Default)]
+struct SystemFonts {
+  /// characters of the faces tried, best first, read on the first missing character
+  candidates: Option<Vec<(ID, Coverage)>>,
+  /// faces loaded so far, in the order they were found
+  faces: Vec<(ID, FontArc)>,
+  /// the face of each character looked up, `None` when no system font has it
+  chars: HashMap<char, Option<ID>>,
+}
+
+/// Font of the chain a glyph comes from, kerning only applies between glyphs of the same one
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Source {
+  Primary,
+  Fallback(usize),
+  System(ID),
+}
+
+/// whether `font` has a glyph for `c`
+fn has_char(font: &impl Font, c: char) -> bool {
+  font.glyph_id(c).0 != 0
+}
+/// the characters of `font`
+fn coverage(font: &impl Font) -> Coverage {
+  let mut chars: Vec<u32> = font
+    .codepoint_ids()
+    .filter(|(id, _)| id.0 != 0)
+    .map(|(_, c)| c as u32)
+    .collect();
+  chars.sort_unstable();
+  let mut ranges = Coverage::new();
+  for c in chars {
+    match ranges.last_mut() {
+      Some(last) if c <= last.1 + 1 => last.1 = c,
+      _ => ranges.push((c, c)),
+    }
+  }
+  ranges
+}
+/// whether the `coverage` ranges contain `c`
+fn covers(ranges: &[(u32, u32)], c: char) -> bool {
+  let c = c as u32;
+  let i = ranges.partition_point(|r| r.1 < c);
+  ranges.get(i).is_some_and(|r| r.0 <= c)
 }
 
 impl TextRender {
+  /// most system faces whose characters are read for the lookup of missing ones
+  const MAX_SYSTEM_FACES: usize = 256;
+
   pub fn new() -> Self {
     let mut db = Database::new();
     db.load_system_fonts();
     let source = include_bytes!("maple.ttf");
-    let font = FontVec::try_from_vec(source.to_vec()).unwrap();
-    Self { font, db }
+    let font = FontArc::try_from_vec(source.to_vec()).unwrap();
+    Self {
+      font,
+      fallbacks: Vec::new(),
+      db,
+      system: Mutex::default(),
+    }
   }
 
   pub fn has_family(&self, family: &str) -> bool {
@@ -30,93 +102,434 @@ impl TextRender {
       ..Default::default()
     };
 
-    if let Some(id) = self.db.query(&query) {
-      self.db.with_face_data(id, |source, index| {
-        self.font = FontVec::try_from_vec_and_index(source.to_vec(), index).unwrap();
-      });
+    if let Some(font) = self.db.query(&query).and_then(|id| self.load_face(id)) {
+      self.font = font;
     } else {
       // load font in the lib
       self.load_default_font();
     }
+    self.system.get_mut().unwrap().chars.clear();
+  }
+  /// Sets the families tried in order for the characters missing from the font of `load_font`,
+  /// before the other system fonts. Families that aren't installed are skipped.
+  pub fn set_fallbacks(&mut self, families: &[&str]) {
+    self.fallbacks = families
+      .iter()
+      .filter_map(|family| {
+        let query = Query {
+          families: &[Family::Name(family)],
+          ..Default::default()
+        };
+        self.db.query(&query).and_then(|id| self.load_face(id))
+      })
+      .collect();
+    self.system.get_mut().unwrap().chars.clear();
   }
 
-  pub fn draw(
-    &self, pixmap: &mut tiny_skia::Pixmap, text: &str, x: f32, y: f32, size: f32,
-    color: tiny_skia::Color,
+  /// whether a font of the chain or of the system has a glyph for `c`
+  pub(crate) fn has_glyph(&self, c: char) -> bool {
+    has_char(&self.font_for(c).1, c)
+  }
+  /// the first font having `c`, the primary one when none has it
+  fn font_for(&self, c: char) -> (Source, FontArc) {
+    if has_char(&self.font, c) {
+      return (Source::Primary, self.font.clone());
+    }
+    if let Some(i) = self.fallbacks.iter().position(|f| has_char(f, c)) {
+      return (Source::Fallback(i), self.fallbacks[i].clone());
+    }
+    match self.system_font(c) {
+      Some((id, font)) => (Source::System(id), font),
+      None => (Source::Primary, self.font.clone()),
+    }
+  }
+  /// a system font having `c`, looked up once per character
+  fn system_font(&self, c: char) -> Option<(ID, FontArc)> {
+    let mut system = self.system.lock().unwrap();
+    let id = match system.chars.get(&c) {
+      Some(&id) => id?,
+      None => {
+        let candidates = system.candidates.get_or_insert_with(|| self.candidates());
+        let id = candidates
+          .iter()
+          .find(|(_, ranges)| covers(ranges, c))
+          .map(|(id, _)| *id);
+        system.chars.insert(c, id);
+        id?
+      }
+    };
+    if let Some((_, font)) = system.faces.iter().find(|(k, _)| *k == id) {
+      return Some((id, font.clone()));
+    }
+    let font = self.load_face(id)?;
+    system.faces.push((id, font.clone()));
+    Some((id, font))
+  }
+  /// The system faces tried for missing characters with their coverage, best first: the
+  /// sans-serif face, then upright faces closest to the regular weight by family name, one face
+  /// per family and at most `MAX_SYSTEM_FACES` of them.
+  fn candidates(&self) -> Vec<(ID, Coverage)> {
+    let sans = self.db.query(&Query {
+      families: &[Family::SansSerif],
+      ..Default::default()
+    });
+    let key = |f: &FaceInfo| {
+      let weight = f.weight.0.abs_diff(Weight::NORMAL.0);
+      (Some(f.id) != sans, f.style != Style::Normal, weight)
+    };
+    let family = |f: &FaceInfo| f.families.first().map(|(name, _)| name.clone());
+    let mut faces: Vec<&FaceInfo> = self.db.faces().collect();
+    faces.sort_by(|a, b| key(a).cmp(&key(b)).then_with(|| family(a).cmp(&family(b))));
+    let mut seen = HashSet::new();
+    faces
+      .into_iter()
+      .filter(|f| seen.insert(family(f)))
+      .take(Self::MAX_SYSTEM_FACES)
+      .filter_map(|f| {
+        let ranges = self.db.with_face_data(f.id, |data, index| {
+          FontRef::try_from_slice_and_index(data, index)
+            .ok()
+            .map(|font| coverage(&font))
+        });
+        Some((f.id, ranges.flatten()?))
+      })
+      .collect()
+  }
+  fn load_face(&self, id: ID) -> Option<FontArc> {
+    self
+      .db
+      .with_face_data(id, |data, index| {
+        FontVec::try_from_vec_and_index(data.to_vec(), index).ok()
+      })
+      .flatten()
+      .map(FontArc::new)
+  }
+
+  /// `(width, height)` in pixels of `text` at `size`, see `metrics`
+  pub fn measure(&self, text: &str, size: f32) -> (f32, f32) {
+    let m = self.metrics(text, size);
+    (m.width, m.height)
+  }
+  /// Size of `text` at `size` laid out in lines split at `\n`, kerning included.
+  pub fn metrics(&self, text: &str, size: f32) -> TextMetrics {
+    self.measure_lines(&self.layout(text, size), size)
+  }
+  fn measure_lines(&self, lines: &[Line], size: f32) -> TextMetrics {
+    let scaled_font = self.font.as_scaled(PxScale::from(size));
+    let width = lines.iter().map(|l| l.width).fold(0.0, f32::max);
+    let line_h = scaled_font.ascent() - scaled_font.descent();
+    let n = lines.len() as f32;
+    TextMetrics {
+      width,
+      height: n * line_h + (n - 1.0) * scaled_font.line_gap(),
+      ascent: scaled_font.ascent(),
+      descent: scaled_font.descent(),
+    }
+  }
+  /// Bounding box of the ink of `text` at `size` from the top left corner of the text, `None`
+  /// for blank text. Every glyph is outlined, unlike `metrics`.
+  pub fn ink_bounds(&self, text: &str, size: f32) -> Option<Rect> {
+    let mut bounds: Option<Rect> = None;
+    for line in self.layout(text, size) {
+      for (font, id, x) in &line.glyphs {
+        let glyph = id.with_scale_and_position(size, ab_glyph::point(*x, line.baseline));
+        if let Some(outlined) = font.outline_glyph(glyph) {
+          let b = outlined.px_bounds();
+          let r = Rect::from_ltrb(b.min.x, b.min.y, b.max.x, b.max.y);
+          bounds = match (bounds, r) {
+            (Some(a), Some(r)) => Rect::from_ltrb(
+              a.left().min(r.left()),
+              a.top().min(r.top()),
+              a.right().max(r.right()),
+              a.bottom().max(r.bottom()),
+            ),
+            (a, r) => a.or(r),
+          };
+        }
+      }
+    }
+    bounds
+  }
+  /// Maps the layout of `text`, `(0, 0)` at the top left corner of its first line, into the
+  /// pixmap: the side given by the alignment of `style` is at `(x, y)`, turned by its rotation.
+  pub fn transform(&self, text: &str, x: f32, y: f32, style: &TextStyle) -> Transform {
+    place(&self.metrics(text, style.size), x, y, style)
+  }
+
+  /// draw `text` with the top left corner of its first line at `(x, y)`
+  pub fn draw(&self, pixmap: &mut Pixmap, text: &str, x: f32, y: f32, size: f32, color: Color) {
+    let style = TextStyle {
+      size,
+      color,
+      ..TextStyle::default()
+    };
+    self.draw_styled(pixmap, text, x, y, &style, None);
+  }
+  /// Draws `text` placed and turned around `(x, y)` as given by `style`, see `transform`.
+  ///
+  /// Lines are split at `\n` and aligned to each other following `style.h_align`.
+  pub fn draw_styled(
+    &self, pixmap: &mut Pixmap, text: &str, x: f32, y: f32, style: &TextStyle, mask: Option<&Mask>,
   ) {
-    let font = &self.font;
-    let scale = ab_glyph::PxScale::from(size);
-    let scaled_font = font.as_scaled(scale);
-
-    let adjusted_y = y + scaled_font.ascent();
-    let mut x_cursor = x;
-
-    // 1. 先把基本信息提取出来，避免在闭包里借用 pixmap
-    let img_w = pixmap.width();
-    let img_h = pixmap.height();
-    let pixels = pixmap.pixels_mut(); // 在这里获取整个像素数组的可变引用
-
-    // 2. 预准备颜色分量
-    let r_f = color.red();
-    let g_f = color.green();
-    let b_f = color.blue();
-    let a_f = color.alpha();
-
-    for c in text.chars() {
-      let glyph_id = font.glyph_id(c);
-      let glyph = glyph_id.with_scale_and_position(
-        size,
-        ab_glyph::Point {
-          x: x_cursor,
-          y: adjusted_y,
-        },
-      );
-
-      if let Some(outlined) = font.outline_glyph(glyph) {
-        let bounds = outlined.px_bounds();
-
-        // 3. 在这里直接操作像素
-        outlined.draw(|ox, oy, coverage| {
-          if coverage > 0.0 {
-            let px = (bounds.min.x as i32) + ox as i32;
-            let py = (bounds.min.y as i32) + oy as i32;
-
-            // 边界检查
-            if px >= 0 && py >= 0 && (px as u32) < img_w && (py as u32) < img_h {
-              let idx = (py as u32 * img_w + px as u32) as usize;
-
-              // 获取当前像素
-              let old_px = pixels[idx];
-
-              // 计算 Alpha 混合
-              // 文字颜色使用 Premultiplied 格式进行混合
-              let alpha = coverage * a_f;
-              let inv_alpha = 1.0 - alpha;
-
-              let out_r = (r_f * alpha + (old_px.red() as f32 / 255.0) * inv_alpha).clamp(0.0, 1.0);
-              let out_g =
-                (g_f * alpha + (old_px.green() as f32 / 255.0) * inv_alpha).clamp(0.0, 1.0);
-              let out_b =
-                (b_f * alpha + (old_px.blue() as f32 / 255.0) * inv_alpha).clamp(0.0, 1.0);
-              let out_a = (alpha + (old_px.alpha() as f32 / 255.0) * inv_alpha).clamp(0.0, 1.0);
-
-              // 直接写回像素内存
-              if let Some(new_color) = tiny_skia::Color::from_rgba(out_r, out_g, out_b, out_a) {
-                pixels[idx] = new_color.premultiply().to_color_u8();
-              }
-            }
+    let lines = self.layout(text, style.size);
+    let m = self.measure_lines(&lines, style.size);
+    let ts = place(&m, x, y, style);
+    let width = m.width;
+    for line in &lines {
+      let indent = match style.h_align {
+        HAlign::Left => 0.0,
+        HAlign::Center => (width - line.width) / 2.0,
+        HAlign::Right => width - line.width,
+      };
+      for (font, id, gx) in &line.glyphs {
+        if style.rotation == 0.0 {
+          // upright glyphs are rasterized by ab_glyph, sharper at small sizes
+          let (px, py) = (ts.tx + indent + gx, ts.ty + line.baseline);
+          fill_glyph(pixmap, (font, *id), (px, py), style, mask);
+        } else {
+          let origin = (indent + gx, line.baseline);
+          fill_outline(pixmap, (font, *id), origin, &ts, style, mask);
+        }
+      }
+    }
+  }
+
+  /// glyphs of each line with their pen x and the baseline y, from the top left of the text,
+  /// the line height is the one of the primary font
+  fn layout(&self, text: &str, size: f32) -> Vec<Line> {
+    let scaled_font = self.font.as_scaled(PxScale::from(size));
+    let advance = scaled_font.ascent() - scaled_font.descent() + scaled_font.line_gap();
+    text
+      .split('\n')
+      .enumerate()
+      .map(|(i, line)| {
+        let mut x = 0.0;
+        let mut last = None;
+        let mut glyphs = Vec::new();
+        for c in line.chars() {
+          let (source, font) = self.font_for(c);
+          let scaled = font.as_scaled(PxScale::from(size));
+          let id = font.glyph_id(c);
+          if let Some((_, last_id)) = last.filter(|&(last_source, _)| last_source == source) {
+            x += scaled.kern(last_id, id);
           }
-        });
+          let advance = scaled.h_advance(id);
+          glyphs.push((font, id, x));
+          x += advance;
+          last = Some((source, id));
+        }
+        Line {
+          glyphs,
+          width: x,
+          baseline: i as f32 * advance + scaled_font.ascent(),
+        }
+      })
+      .collect()
+  }
+}
+
+/// see `TextRender::transform`, for a text of metrics `m`
+fn place(m: &TextMetrics, x: f32, y: f32, style: &TextStyle) -> Transform {
+  let dx = match style.h_align {
+    HAlign::Left => 0.0,
+    HAlign::Center => -m.width / 2.0,
+    HAlign::Right => -m.width,
+  };
+  let dy = match style.v_align {
+    VAlign::Top => 0.0,
+    VAlign::Center => -m.height / 2.0,
+    VAlign::Baseline => -m.ascent,
+    VAlign::Bottom => -m.height,
+  };
+  Transform::from_translate(x, y)
+    .pre_rotate(-style.rotation)
+    .pre_translate(dx, dy)
+}
+
+/// blend the coverage of an upright glyph with its origin at `(x, y)` into the pixmap
+fn fill_glyph(
+  pixmap: &mut Pixmap, (font, id): (&FontArc, GlyphId), (x, y): (f32, f32), style: &TextStyle,
+  mask: Option<&Mask>,
+) {
+  let glyph = id.with_scale_and_position(style.size, ab_glyph::point(x, y));
+  let Some(outlined) = font.outline_glyph(glyph) else {
+    return;
+  };
+  let bounds = outlined.px_bounds();
+
+  // 1. 先把基本信息提取出来，避免在闭包里借用 pixmap
+  let img_w = pixmap.width();
+  let img_h = pixmap.height();
+  let pixels = pixmap.pixels_mut(); // 在这里获取整个像素数组的可变引用
+
+  // 2. 预准备颜色分量
+  let r_f = style.color.red();
+  let g_f = style.color.green();
+  let b_f = style.color.blue();
+  let a_f = style.color.alpha();
+
+  // 3. 在这里直接操作像素
+  outlined.draw(|ox, oy, coverage| {
+    if coverage > 0.0 {
+      let px = (bounds.min.x as i32) + ox as i32;
+      let py = (bounds.min.y as i32) + oy as i32;
+
+      // 边界检查
+      if px >= 0 && py >= 0 && (px as u32) < img_w && (py as u32) < img_h {
+        let idx = (py as u32 * img_w + px as u32) as usize;
+        let clip = mask
+          .and_then(|m| m.data().get(idx))
+          .map_or(1.0, |&v| v as f32 / 255.0);
+
+        // 获取当前像素
+        let old_px = pixels[idx];
+
+        // 计算 Alpha 混合：预乘颜色的 source-over，透明的目标也成立
+        let alpha = coverage * a_f * clip;
+        let inv_alpha = 1.0 - alpha;
+        let over = |src: f32, dst: u8| {
+          ((src * alpha + dst as f32 / 255.0 * inv_alpha).clamp(0.0, 1.0) * 255.0).round() as u8
+        };
+        let out_a = over(1.0, old_px.alpha());
+        // 预乘后的分量不能超过 alpha
+        let out_r = over(r_f, old_px.red()).min(out_a);
+        let out_g = over(g_f, old_px.green()).min(out_a);
+        let out_b = over(b_f, old_px.blue()).min(out_a);
+
+        // 直接写回像素内存
+        if let Some(new_color) = PremultipliedColorU8::from_rgba(out_r, out_g, out_b, out_a) {
+          pixels[idx] = new_color;
+        }
       }
-      x_cursor += scaled_font.h_advance(glyph_id);
+    }
+  });
+}
+/// fill the outline of a glyph with its origin at `(x, y)` of the layout, mapped by `ts`
+fn fill_outline(
+  pixmap: &mut Pixmap, (font, id): (&FontArc, GlyphId), (x, y): (f32, f32), ts: &Transform,
+  style: &TextStyle, mask: Option<&Mask>,
+) {
+  let Some(outline) = font.outline(id) else {
+    return;
+  };
+  let scale = font.as_scaled(PxScale::from(style.size)).scale_factor();
+  // font units grow upward from the origin
+  let map = |p: ab_glyph::Point| (x + p.x * scale.horizontal, y - p.y * scale.vertical);
+  let mut pb = PathBuilder::new();
+  let mut last: Option<ab_glyph::Point> = None;
+  for curve in &outline.curves {
+    let (start, end) = match *curve {
+      OutlineCurve::Line(p0, p1) => (p0, p1),
+      OutlineCurve::Quad(p0, _, p2) => (p0, p2),
+      OutlineCurve::Cubic(p0, _, _, p3) => (p0, p3),
+    };
+    if last != Some(start) {
+      let (sx, sy) = map(start);
+      pb.move_to(sx, sy);
+    }
+    match *curve {
+      OutlineCurve::Line(_, p1) => {
+        let (x1, y1) = map(p1);
+        pb.line_to(x1, y1);
+      }
+      OutlineCurve::Quad(_, p1, p2) => {
+        let ((x1, y1), (x2, y2)) = (map(p1), map(p2));
+        pb.quad_to(x1, y1, x2, y2);
+      }
+      OutlineCurve::Cubic(_, p1, p2, p3) => {
+        let ((x1, y1), (x2, y2), (x3, y3)) = (map(p1), map(p2), map(p3));
+        pb.cubic_to(x1, y1, x2, y2, x3, y3);
+      }
+    }
+    last = Some(end);
+  }
+  let Some(path) = pb.finish() else {
+    return;
+  };
+  let mut paint = Paint::default();
+  paint.set_color(style.color);
+  paint.anti_alias = true;
+  pixmap.fill_path(&path, &paint, FillRule::Winding, *ts, mask);
+}
+
+/// A line of laid out glyphs
+struct Line {
+  /// glyphs with their font and the x of their origin
+  glyphs: Vec<(FontArc, GlyphId, f32)>,
+  /// advance width, kerning included
+  width: f32,
+  /// y of the baseline from the top of the text
+  baseline: f32,
+}
+
+/// Horizontal side of a text at its position
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum HAlign {
+  #[default]
+  Left,
+  Center,
+  Right,
+}
+
+/// Vertical side of a text at its position
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum VAlign {
+  #[default]
+  Top,
+  Center,
+  /// baseline of the first line
+  Baseline,
+  Bottom,
+}
+
+/// Size of a laid out text in pixels, from `TextRender::metrics`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TextMetrics {
+  /// advance width of the widest line
+  pub width: f32,
+  /// from the ascent of the first line to the descent of the last one
+  pub height: f32,
+  /// above the baseline
+  pub ascent: f32,
+  /// below the baseline, negative
+  pub descent: f32,
+}
+
+/// How `TextRender::draw_styled` places and paints a text
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TextStyle {
+  /// font size in pixels
+  pub size: f32,
+  pub color: Color,
+  pub h_align: HAlign,
+  pub v_align: VAlign,
+  /// degrees, counter-clockwise around the position
+  pub rotation: f32,
+}
+
+impl Default for TextStyle {
+  fn default() -> Self {
+    let [r, g, b, a] = color::get_fg();
+    Self {
+      size: 12.0,
+      color: Color::from_rgba8(r, g, b, a),
+      h_align: HAlign::default(),
+      v_align: VAlign::default(),
+      rotation: 0.0,
     }
   }
 }
 
+impl Default for TextRender {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
 impl TextRender {
   fn load_default_font(&mut self) {
     let source = include_bytes!("maple.ttf");
-    match FontVec::try_from_vec(source.to_vec()) {
+    match FontArc::try_from_vec(source.to_vec()) {
       Ok(font) => self.font = font,
       Err(e) => println!("Error loading font: {}", e),
     }
@@ -129,3 +542,59 @@ fn test_has_font() {
 
   assert!(!tr.has_family("FiraCode"));
 }
+
+#[test]
+fn test_font_fallback() {
+  let mut tr = TextRender::new();
+  assert_eq!(tr.font_for('a').0, Source::Primary);
+  // sorted ranges with a gap between each other
+  let ranges = coverage(&tr.font);
+  assert!(covers(&ranges, 'a') && !covers(&ranges, 'π') && !covers(&ranges, '\u{10FFFD}'));
+  assert!(ranges.windows(2).all(|w| w[0].1 + 1 < w[1].0));
+  // missing from every font, drawn as the missing glyph of the primary one
+  assert!(!tr.has_glyph('\u{10FFFD}'));
+  assert_eq!(tr.font_for('\u{10FFFD}').0, Source::Primary);
+  // the miss is remembered, the system faces aren't searched again
+  let system = tr.system.get_mut().unwrap();
+  assert_eq!(system.chars.get(&'\u{10FFFD}'), Some(&None));
+  let candidates = system.candidates.as_ref().unwrap();
+  assert!(candidates.len() <= TextRender::MAX_SYSTEM_FACES);
+  tr.set_fallbacks(&["No Such Font"]);
+  assert!(tr.fallbacks.is_empty());
+
+  // the embedded font has no `π`, an installed font stands in when there is one
+  let (source, font) = tr.font_for('π');
+  assert_eq!(source == Source::Primary, !has_char(&font, 'π'));
+  if source != Source::Primary {
+    // fallbacks come before the other system fonts
+    tr.fallbacks.push(font);
+    assert_eq!(tr.font_for('π').0, Source::Fallback(0));
+    assert!(tr.measure("2π", 12.0).0 > tr.measure("2", 12.0).0);
+  }
+}
+
+#[test]
+fn test_text_layout() {
+  let tr = TextRender::new();
+  let one = tr.metrics("peak", 12.0);
+  let two = tr.metrics("peak\nvalue 10", 12.0);
+  assert_eq!(two.width, tr.metrics("value 10", 12.0).width);
+  assert!(two.height > 2.0 * one.height - 1e-3);
+  let bounds = tr.ink_bounds("peak", 12.0).unwrap();
+  assert!(bounds.top() >= 0.0 && bounds.bottom() <= one.height);
+
+  // the bottom right corner at the position, turned upright by 90 degrees
+  let style = TextStyle {
+    h_align: HAlign::Right,
+    v_align: VAlign::Bottom,
+    rotation: 90.0,
+    ..TextStyle::default()
+  };
+  let ts = tr.transform("peak", 100.0, 100.0, &style);
+  let mut corner = tiny_skia::Point::from_xy(one.width, one.height);
+  ts.map_point(&mut corner);
+  assert!((corner.x - 100.0).abs() < 1e-3 && (corner.y - 100.0).abs() < 1e-3);
+  let mut start = tiny_skia::Point::from_xy(0.0, one.height);
+  ts.map_point(&mut start);
+  assert!((start.y - (100.0 + one.width)).abs() < 1e-3);
+}
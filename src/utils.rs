@@ -36,3 +36,12 @@ fn _sin(mut x: f32) -> f32 {
 pub fn sin(x: &[f32]) -> Vec<f32> {
   x.iter().map(|&v| _sin(v)).collect()
 }
+
+/// linear interpolated quantile of `sorted` data, `q` in `[0, 1]`
+/// * `sorted` must be sorted ascending and not empty
+pub(crate) fn quantile(sorted: &[f32], q: f32) -> f32 {
+  let pos = q.clamp(0.0, 1.0) * (sorted.len() - 1) as f32;
+  let lo = pos.floor() as usize;
+  let hi = pos.ceil() as usize;
+  sorted[lo] + (sorted[hi] - sorted[lo]) * (pos - lo as f32)
+}
@@ -0,0 +1,125 @@
+use tiny_skia::Point;
+
+use crate::drawable::Bound3D;
+
+/// Orthographic mapping of `(x, y, z)` data into pixels, handed to `Drawable::draw_3d`
+/// * the data limits are scaled into a cube, so the box keeps its shape when rotated
+/// * the camera looks at the center of the cube from `elevation` above the x-y plane,
+///   turned by `azimuth` around the z axis, both in degrees
+pub struct View3D {
+  center: Point,
+  /// pixels of half a side of the cube
+  scale: f32,
+  limits: [(f32, f32); 3],
+  /// screen right, screen up and toward the viewer, in cube coordinates
+  right: [f32; 3],
+  up: [f32; 3],
+  toward: [f32; 3],
+}
+
+impl View3D {
+  pub(crate) fn new(
+    center: Point, scale: f32, limits: [(f32, f32); 3], elevation: f32, azimuth: f32,
+  ) -> Self {
+    let (elev, azim) = (elevation.to_radians(), azimuth.to_radians());
+    let (se, ce) = elev.sin_cos();
+    let (sa, ca) = azim.sin_cos();
+    Self {
+      center,
+      scale,
+      limits,
+      right: [-sa, ca, 0.0],
+      up: [-se * ca, -se * sa, ce],
+      toward: [ce * ca, ce * sa, se],
+    }
+  }
+  /// data scaled into the cube `[-1, 1]³`
+  pub(crate) fn normalize(&self, p: [f32; 3]) -> [f32; 3] {
+    let mut n = [0.0; 3];
+    for k in 0..3 {
+      let (lo, hi) = self.limits[k];
+      n[k] = (p[k] - lo) / (hi - lo).max(1e-6) * 2.0 - 1.0;
+    }
+    n
+  }
+  /// pixel position of a data point
+  pub(crate) fn map(&self, x: f32, y: f32, z: f32) -> Point {
+    let n = self.normalize([x, y, z]);
+    Point::from_xy(
+      self.center.x + dot(n, self.right) * self.scale,
+      self.center.y - dot(n, self.up) * self.scale,
+    )
+  }
+  /// distance toward the viewer, larger is closer, used to draw from back to front
+  pub(crate) fn depth(&self, x: f32, y: f32, z: f32) -> f32 {
+    dot(self.normalize([x, y, z]), self.toward)
+  }
+  /// direction toward the viewer in cube coordinates
+  pub(crate) fn toward(&self) -> [f32; 3] {
+    self.toward
+  }
+  /// brightness in `0.3..=1.0` of a face with the normal `n` in cube coordinates,
+  /// lit from above and in front of the viewer
+  pub(crate) fn shade(&self, n: [f32; 3]) -> f32 {
+    let len = dot(n, n).sqrt();
+    if len < 1e-9 {
+      return 1.0;
+    }
+    let light = [
+      self.toward[0] + self.up[0],
+      self.toward[1] + self.up[1],
+      self.toward[2] + self.up[2],
+    ];
+    let l_len = dot(light, light).sqrt();
+    0.3 + 0.7 * (dot(n, light) / (len * l_len)).abs()
+  }
+}
+
+/// bound of the finite points of three coordinate slices
+pub(crate) fn bound_of(x: &[f32], y: &[f32], z: &[f32]) -> Option<Bound3D> {
+  let mut b = Bound3D {
+    x_min: f32::INFINITY,
+    x_max: f32::NEG_INFINITY,
+    y_min: f32::INFINITY,
+    y_max: f32::NEG_INFINITY,
+    z_min: f32::INFINITY,
+    z_max: f32::NEG_INFINITY,
+  };
+  for ((&x, &y), &z) in x.iter().zip(y).zip(z) {
+    if !(x.is_finite() && y.is_finite() && z.is_finite()) {
+      continue;
+    }
+    (b.x_min, b.x_max) = (b.x_min.min(x), b.x_max.max(x));
+    (b.y_min, b.y_max) = (b.y_min.min(y), b.y_max.max(y));
+    (b.z_min, b.z_max) = (b.z_min.min(z), b.z_max.max(z));
+  }
+  b.x_min.is_finite().then_some(b)
+}
+
+pub(crate) fn dot(a: [f32; 3], b: [f32; 3]) -> f32 {
+  a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+pub(crate) fn cross(a: [f32; 3], b: [f32; 3]) -> [f32; 3] {
+  [
+    a[1] * b[2] - a[2] * b[1],
+    a[2] * b[0] - a[0] * b[2],
+    a[0] * b[1] - a[1] * b[0],
+  ]
+}
+
+#[test]
+fn test_view3d_map() {
+  let center = Point::from_xy(100.0, 100.0);
+  let limits = [(0.0, 10.0), (0.0, 10.0), (0.0, 10.0)];
+  // looking down the x axis: y goes right, z goes up
+  let side = View3D::new(center, 50.0, limits, 0.0, 0.0);
+  let p = side.map(5.0, 10.0, 10.0);
+  assert!((p.x - 150.0).abs() < 1e-3 && (p.y - 50.0).abs() < 1e-3);
+  assert!(side.depth(10.0, 5.0, 5.0) > side.depth(0.0, 5.0, 5.0));
+
+  // looking from the top: x goes right, y goes up
+  let top = View3D::new(center, 50.0, limits, 90.0, -90.0);
+  let p = top.map(10.0, 0.0, 5.0);
+  assert!((p.x - 150.0).abs() < 1e-3 && (p.y - 150.0).abs() < 1e-3);
+}